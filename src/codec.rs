@@ -1,21 +1,24 @@
 mod asset;
 mod error;
 mod reader;
+mod schema;
 mod writer;
 
 pub(crate) use reader::Reader;
+pub(crate) use schema::Schema;
 pub(crate) use writer::Writer;
 
-pub use asset::ArchiveSource;
+pub use asset::AssetSize;
 pub use asset::AssetSnap;
 pub use asset::AssetSource;
-pub use asset::EmptySource;
 pub use asset::Stream;
 pub use error::Error;
 pub use error::Result;
 
 use crate::codec;
+use serde_json::Map;
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::marker::Sized;
 
 pub trait Codec: Sized {
@@ -57,6 +60,76 @@ impl Codec for f32 {
     }
 }
 
+impl Codec for bool {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(*self);
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        reader.value().as_bool().ok_or(codec::Error::MismatchType {
+            expected: "a boolean".to_string(),
+            found: reader.value().to_string(),
+        })
+    }
+}
+
+impl Codec for u32 {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(*self);
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        reader
+            .value()
+            .as_u64()
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or(codec::Error::MismatchType {
+                expected: "u32".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Codec for u64 {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(*self);
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        reader.value().as_u64().ok_or(codec::Error::MismatchType {
+            expected: "u64".to_string(),
+            found: reader.value().to_string(),
+        })
+    }
+}
+
+impl Codec for (f32, f32) {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(vec![self.0 as f64, self.1 as f64]);
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.value().as_array().map(Vec::as_slice) {
+            Some([x, y]) => x.as_f64().zip(y.as_f64()),
+
+            _ => None,
+        }
+        .map(|(x, y)| (x as f32, y as f32))
+        .ok_or(codec::Error::MismatchType {
+            expected: "a pair of f32".to_string(),
+            found: reader.value().to_string(),
+        })
+    }
+}
+
 impl Codec for Value {
     fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
         writer.value(self.clone());
@@ -140,3 +213,39 @@ where
             .collect()
     }
 }
+
+impl<T> Codec for BTreeMap<String, T>
+where
+    T: Codec,
+{
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(
+            self.iter()
+                .map(|(key, item)| {
+                    let mut writer = writer.clone();
+
+                    Codec::encode(item, &mut writer)?;
+
+                    let (_, value) = writer.end();
+
+                    Ok((key.clone(), value))
+                })
+                .collect::<codec::Result<Map<String, Value>>>()?,
+        );
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        reader
+            .value()
+            .as_object()
+            .ok_or(codec::Error::MismatchType {
+                expected: "an object".to_string(),
+                found: reader.value().to_string(),
+            })?
+            .iter()
+            .map(|(key, item)| Ok((key.clone(), T::decode(&reader.clone(item))?)))
+            .collect()
+    }
+}
@@ -1,113 +1,97 @@
 use crate::codec;
+use std::io::Cursor;
 use std::io::Read;
 use std::io::Seek;
 use std::io::Write;
 use std::sync::Arc;
-use std::sync::Mutex;
-use zip::ZipArchive;
+use zip::CompressionMethod;
+use zip::DateTime;
 use zip::ZipWriter;
+use zip::write::FileOptions;
 
 pub trait Stream: Write + Seek {}
 
 impl<T> Stream for T where T: Write + Seek {}
 
-pub trait AssetSource: Send + Sync {
-    fn load(&self, path: &str) -> codec::Result<Vec<u8>>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetSize {
+    compressed: Option<u64>,
 
-    fn copy(&self, path: &str, writer: &mut ZipWriter<&mut dyn Stream>) -> codec::Result<()>;
+    uncompressed: u64,
 }
 
-pub struct ArchiveSource<R>
-where
-    R: Read + Seek,
-{
-    archive: Arc<Mutex<ZipArchive<R>>>,
-}
+impl AssetSize {
+    pub fn new(compressed: Option<u64>, uncompressed: u64) -> Self {
+        AssetSize {
+            compressed,
 
-impl<R> ArchiveSource<R>
-where
-    R: Read + Seek,
-{
-    pub fn new(archive: ZipArchive<R>) -> Self {
-        ArchiveSource {
-            archive: Arc::new(Mutex::new(archive)),
+            uncompressed,
         }
     }
+
+    pub fn compressed(&self) -> Option<u64> {
+        self.compressed
+    }
+
+    pub fn uncompressed(&self) -> u64 {
+        self.uncompressed
+    }
 }
 
-impl<R> AssetSource for ArchiveSource<R>
-where
-    R: Read + Seek + Send + 'static,
-{
-    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
-        let mut archive = self
-            .archive
-            .lock()
-            .map_err(|_| codec::Error::ArchiveAcquireFailed)?;
-
-        let mut stream = match archive.by_name(path) {
-            Ok(val) => val,
-
-            Err(_) => {
-                return Err(codec::Error::AssetNotFound {
-                    path: path.to_string(),
-                });
-            }
-        };
-
-        let mut data = Vec::new();
-
-        if stream.read_to_end(&mut data).is_err() {
-            return Err(codec::Error::AssetLoadFailed {
-                path: path.to_string(),
-            });
-        }
+pub trait AssetSource: Send + Sync {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>>;
 
-        Ok(data)
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        Ok(Box::new(Cursor::new(self.load(path)?)))
     }
 
-    fn copy(&self, path: &str, writer: &mut ZipWriter<&mut dyn Stream>) -> codec::Result<()> {
-        let mut archive = self
-            .archive
-            .lock()
-            .map_err(|_| codec::Error::ArchiveAcquireFailed)?;
+    fn contains(&self, path: &str) -> bool {
+        self.load(path).is_ok()
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        Ok(AssetSize::new(None, self.load(path)?.len() as u64))
+    }
 
-        let stream = match archive.by_name(path) {
-            Ok(val) => val,
+    fn copy(
+        &self,
+        path: &str,
+        name: &str,
+        writer: &mut ZipWriter<&mut dyn Stream>,
+    ) -> codec::Result<()> {
+        let data = self.load(path)?;
 
-            Err(_) => {
-                return Err(codec::Error::AssetNotFound {
-                    path: path.to_string(),
-                });
-            }
-        };
+        let options = FileOptions::<()>::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default());
 
         writer
-            .raw_copy_file(stream)
+            .start_file(name, options)
+            .map_err(|_| codec::Error::AssetCopyFailed {
+                path: path.to_string(),
+            })?;
+
+        writer
+            .write_all(&data)
             .map_err(|_| codec::Error::AssetCopyFailed {
                 path: path.to_string(),
             })
     }
 }
 
-pub struct EmptySource;
+#[derive(Clone)]
+pub enum AssetSnap {
+    Clean(Arc<dyn AssetSource>, String),
+    Dirty(Arc<[u8]>),
+}
 
-impl AssetSource for EmptySource {
-    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
-        Err(codec::Error::AssetNotFound {
-            path: path.to_string(),
-        })
-    }
+impl AssetSnap {
+    pub(crate) fn same(&self, other: &AssetSnap) -> bool {
+        match (self, other) {
+            (AssetSnap::Clean(a, x), AssetSnap::Clean(b, y)) => Arc::ptr_eq(a, b) && x == y,
+            (AssetSnap::Dirty(a), AssetSnap::Dirty(b)) => Arc::ptr_eq(a, b) || a == b,
 
-    fn copy(&self, path: &str, _: &mut ZipWriter<&mut dyn Stream>) -> codec::Result<()> {
-        Err(codec::Error::AssetNotFound {
-            path: path.to_string(),
-        })
+            _ => false,
+        }
     }
 }
-
-#[derive(Clone)]
-pub enum AssetSnap {
-    Clean(Arc<dyn AssetSource>),
-    Dirty(Vec<u8>),
-}
@@ -22,12 +22,26 @@ pub enum Error {
     #[error("failed to copy asset at `{path}`")]
     AssetCopyFailed { path: String },
 
+    #[error("conflicting assets at `{path}`")]
+    AssetConflict { path: String },
+
+    #[error("failed to open archive")]
+    ArchiveOpenFailed,
+
     #[error("failed to acquire archive")]
     ArchiveAcquireFailed,
 
     #[error("failed to access asset at `{path}`")]
     AssetAccessFailed { path: String },
 
+    #[error("failed to convert extension `{key}` in `{namespace}`: {message}")]
+    ExtensionConvertFailed {
+        namespace: String,
+        key: String,
+
+        message: String,
+    },
+
     #[error("unknown error")]
     Undefined,
 }
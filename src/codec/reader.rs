@@ -1,13 +1,11 @@
 use crate::Codec;
 use crate::codec;
-use crate::codec::ArchiveSource;
 use crate::codec::AssetSource;
 use crate::file::Manifest;
+use crate::project::AssetCache;
+use crate::project::Session;
 use serde_json::Value;
-use std::io::Read;
-use std::io::Seek;
 use std::sync::Arc;
-use zip::ZipArchive;
 
 pub struct Reader<'a> {
     manifest: &'a Manifest,
@@ -15,19 +13,28 @@ pub struct Reader<'a> {
     value: &'a Value,
 
     source: Arc<dyn AssetSource>,
+
+    cache: Arc<AssetCache>,
+
+    session: Arc<Session>,
 }
 
 impl<'a> Reader<'a> {
-    pub fn new<R>(manifest: &'a Manifest, value: &'a Value, archive: ZipArchive<R>) -> Reader<'a>
-    where
-        R: Read + Seek + Send + 'static,
-    {
+    pub fn new(
+        manifest: &'a Manifest,
+        value: &'a Value,
+        source: Arc<dyn AssetSource>,
+    ) -> Reader<'a> {
         Reader {
             manifest,
 
             value,
 
-            source: Arc::new(ArchiveSource::new(archive)),
+            source,
+
+            cache: Arc::new(AssetCache::default()),
+
+            session: Arc::new(Session::default()),
         }
     }
 
@@ -52,6 +59,18 @@ impl<'a> Reader<'a> {
         Codec::decode(&reader)
     }
 
+    pub fn field_or_default<K, T>(&self, key: K) -> codec::Result<T>
+    where
+        K: AsRef<str>,
+        T: Codec + Default,
+    {
+        match self.value.get(key.as_ref()) {
+            Some(value) => Codec::decode(&self.clone(value)),
+
+            None => Ok(T::default()),
+        }
+    }
+
     pub fn value(&self) -> &Value {
         self.value
     }
@@ -60,6 +79,14 @@ impl<'a> Reader<'a> {
         Arc::clone(&self.source)
     }
 
+    pub fn cache(&self) -> Arc<AssetCache> {
+        Arc::clone(&self.cache)
+    }
+
+    pub fn session(&self) -> Arc<Session> {
+        Arc::clone(&self.session)
+    }
+
     pub fn clone(&self, value: &'a Value) -> Reader<'a> {
         Reader {
             manifest: self.manifest,
@@ -67,6 +94,10 @@ impl<'a> Reader<'a> {
             value,
 
             source: Arc::clone(&self.source),
+
+            cache: Arc::clone(&self.cache),
+
+            session: Arc::clone(&self.session),
         }
     }
 }
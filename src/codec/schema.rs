@@ -0,0 +1,93 @@
+use crate::codec;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+pub trait Schema {
+    fn schema(version: u8) -> codec::Result<Value>;
+}
+
+impl Schema for String {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({ "type": "string" }))
+    }
+}
+
+impl Schema for f32 {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({ "type": "number" }))
+    }
+}
+
+impl Schema for bool {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({ "type": "boolean" }))
+    }
+}
+
+impl Schema for u32 {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+}
+
+impl Schema for u64 {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({ "type": "integer", "minimum": 0 }))
+    }
+}
+
+impl Schema for (f32, f32) {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": 2,
+            "maxItems": 2,
+        }))
+    }
+}
+
+impl Schema for Value {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({}))
+    }
+}
+
+impl<T> Schema for Option<T>
+where
+    T: Schema,
+{
+    fn schema(version: u8) -> codec::Result<Value> {
+        Ok(json!({
+            "anyOf": [
+                T::schema(version)?,
+                { "type": "null" },
+            ]
+        }))
+    }
+}
+
+impl<T> Schema for Vec<T>
+where
+    T: Schema,
+{
+    fn schema(version: u8) -> codec::Result<Value> {
+        Ok(json!({
+            "type": "array",
+            "items": T::schema(version)?,
+        }))
+    }
+}
+
+impl<T> Schema for BTreeMap<String, T>
+where
+    T: Schema,
+{
+    fn schema(version: u8) -> codec::Result<Value> {
+        Ok(json!({
+            "type": "object",
+            "additionalProperties": T::schema(version)?,
+        }))
+    }
+}
@@ -4,11 +4,11 @@ use crate::codec::AssetSnap;
 use crate::file::Manifest;
 use serde_json::Map;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
-type Assets = Arc<Mutex<HashMap<String, AssetSnap>>>;
+type Assets = Arc<Mutex<BTreeMap<String, AssetSnap>>>;
 
 pub struct Writer<'a> {
     manifest: &'a Manifest,
@@ -25,7 +25,7 @@ impl<'a> Writer<'a> {
 
             value: Value::Null,
 
-            assets: Arc::new(Mutex::new(HashMap::new())),
+            assets: Arc::new(Mutex::new(BTreeMap::new())),
         }
     }
 
@@ -62,9 +62,17 @@ impl<'a> Writer<'a> {
         self.value = value.into();
     }
 
-    pub fn asset(&mut self, path: String, snap: AssetSnap) {
-        if let Ok(mut assets) = self.assets.lock() {
-            assets.insert(path, snap);
+    pub fn asset(&mut self, path: String, snap: AssetSnap) -> codec::Result<()> {
+        let mut assets = self.assets.lock().map_err(|_| codec::Error::Undefined)?;
+
+        match assets.get(&path) {
+            Some(prev) if !prev.same(&snap) => Err(codec::Error::AssetConflict { path }),
+
+            _ => {
+                assets.insert(path, snap);
+
+                Ok(())
+            }
         }
     }
 
@@ -1,18 +1,27 @@
 mod check;
+mod encoding;
 mod error;
 mod manifest;
 mod open;
 mod save;
+mod schema;
+mod validate;
 
+pub use encoding::Encoding;
 pub use error::Error;
 pub use error::Result;
 pub use manifest::Manifest;
+pub use validate::Violation;
 
 pub use check::check_from_path;
 pub use check::check_from_stream;
 pub use open::open_from_path;
+pub use open::open_from_path_with_source;
 pub use open::open_from_stream;
+pub use open::open_from_stream_with_source;
 pub use save::save_to_path;
 pub use save::save_to_stream;
+pub use schema::schema;
+pub use validate::validate;
 
 const IDENTIFIER: &str = "Cangyan Project Package";
@@ -0,0 +1,121 @@
+use crate::file;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::Read;
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    #[default]
+    Json,
+    Cbor,
+    MessagePack,
+}
+
+impl Encoding {
+    pub const ALL: [Encoding; 3] = [Encoding::Json, Encoding::Cbor, Encoding::MessagePack];
+
+    pub fn file(&self) -> &'static str {
+        match self {
+            Encoding::Json => "project.json",
+            Encoding::Cbor => "project.cbor",
+            Encoding::MessagePack => "project.msgpack",
+        }
+    }
+
+    pub(crate) fn encode(&self, value: &Value) -> file::Result<Vec<u8>> {
+        match self {
+            Encoding::Json => {
+                serde_json::to_vec_pretty(value).map_err(|err| file::Error::ParseFailure {
+                    file: self.file().to_string(),
+                    line: err.line(),
+                    column: err.column(),
+                })
+            }
+
+            Encoding::Cbor => {
+                let mut data = Vec::new();
+
+                ciborium::into_writer(value, &mut data).map_err(|_| {
+                    file::Error::EncodeFailure {
+                        file: self.file().to_string(),
+                    }
+                })?;
+
+                Ok(data)
+            }
+
+            Encoding::MessagePack => {
+                rmp_serde::to_vec_named(value).map_err(|_| file::Error::EncodeFailure {
+                    file: self.file().to_string(),
+                })
+            }
+        }
+    }
+
+    pub(crate) fn decode<R>(&self, stream: R) -> file::Result<Value>
+    where
+        R: Read,
+    {
+        match self {
+            Encoding::Json => {
+                serde_json::from_reader(stream).map_err(|err| file::Error::ParseFailure {
+                    file: self.file().to_string(),
+                    line: err.line(),
+                    column: err.column(),
+                })
+            }
+
+            Encoding::Cbor => {
+                ciborium::from_reader(stream).map_err(|_| file::Error::DecodeFailure {
+                    file: self.file().to_string(),
+                })
+            }
+
+            Encoding::MessagePack => {
+                rmp_serde::from_read(stream).map_err(|_| file::Error::DecodeFailure {
+                    file: self.file().to_string(),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file::Encoding;
+    use serde_json::json;
+
+    #[test]
+    fn roundtrip() {
+        let value = json!({
+            "cover": null,
+            "title": "Project Title",
+            "pages": [
+                {
+                    "image": "image1.png",
+                    "notes": [
+                        {
+                            "x": 0.5,
+                            "y": 0.25,
+                            "texts": []
+                        }
+                    ]
+                }
+            ]
+        });
+
+        for encoding in Encoding::ALL {
+            let data = match encoding.encode(&value) {
+                Ok(val) => val,
+                Err(err) => panic!("Failed to encode {:?}: {:?}", encoding, err),
+            };
+
+            match encoding.decode(data.as_slice()) {
+                Ok(decoded) => assert_eq!(decoded, value),
+                Err(err) => panic!("Failed to decode {:?}: {:?}", encoding, err),
+            }
+        }
+    }
+}
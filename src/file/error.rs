@@ -37,6 +37,12 @@ pub enum Error {
         column: usize,
     },
 
+    #[error("failed to encode file `{}`", file)]
+    EncodeFailure { file: String },
+
+    #[error("failed to decode file `{}`", file)]
+    DecodeFailure { file: String },
+
     #[error("{}", source)]
     CodecError {
         #[from]
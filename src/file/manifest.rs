@@ -1,9 +1,13 @@
+use crate::file::Encoding;
 use serde::Deserialize;
 use serde::Serialize;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Manifest {
     version: u8,
+
+    #[serde(default)]
+    encoding: Encoding,
 }
 
 impl Manifest {
@@ -24,4 +28,18 @@ impl Manifest {
 
         self
     }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+
+        self
+    }
 }
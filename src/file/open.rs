@@ -2,16 +2,60 @@ use crate::Codec;
 use crate::Project;
 use crate::codec::Reader;
 use crate::file;
+use crate::file::Encoding;
+use crate::file::Manifest;
+use crate::source::ArchiveSource;
+use crate::source::AssetSource;
+use crate::source::Shared;
+use serde_json::Value;
 use std::fs::File;
 use std::io;
 use std::io::Read;
 use std::io::Seek;
+use std::iter;
 use std::path::Path;
+use std::sync::Arc;
 use zip::ZipArchive;
 
 pub fn open_from_path<P: AsRef<Path>>(path: P) -> file::Result<Project> {
-    let path = path.as_ref();
+    open_shared(Shared::from_file(open_file(path.as_ref())?))
+}
+
+pub fn open_from_path_with_source<P: AsRef<Path>>(
+    path: P,
+    source: Arc<dyn AssetSource>,
+) -> file::Result<Project> {
+    open_from_stream_with_source(open_file(path.as_ref())?, source)
+}
+
+pub fn open_from_stream<R: Read + Seek + Send + 'static>(stream: R) -> file::Result<Project> {
+    open_shared(Shared::new(stream))
+}
+
+pub fn open_from_stream_with_source<R: Read + Seek>(
+    stream: R,
+    source: Arc<dyn AssetSource>,
+) -> file::Result<Project> {
+    let mut archive = open_archive(stream)?;
+
+    let (manifest, value) = read(&mut archive)?;
+
+    decode(&manifest, &value, source)
+}
+
+fn open_shared(stream: Shared) -> file::Result<Project> {
+    let mut archive = open_archive(stream)?;
 
+    let (manifest, value) = read(&mut archive)?;
+
+    decode(
+        &manifest,
+        &value,
+        Arc::new(ArchiveSource::from_archive(archive)),
+    )
+}
+
+fn open_file(path: &Path) -> file::Result<File> {
     if !path.exists() {
         return Err(file::Error::PathNotExist {
             path: path.to_path_buf(),
@@ -24,49 +68,37 @@ pub fn open_from_path<P: AsRef<Path>>(path: P) -> file::Result<Project> {
         });
     }
 
-    let file = match File::open(path) {
-        Ok(val) => val,
+    match File::open(path) {
+        Ok(val) => Ok(val),
 
         Err(err) => match err.kind() {
-            io::ErrorKind::PermissionDenied => {
-                return Err(file::Error::PermissionDenied {
-                    path: path.to_path_buf(),
-                });
-            }
+            io::ErrorKind::PermissionDenied => Err(file::Error::PermissionDenied {
+                path: path.to_path_buf(),
+            }),
 
-            _ => {
-                return Err(file::Error::Undefined);
-            }
+            _ => Err(file::Error::Undefined),
         },
-    };
-
-    open_from_stream(file)
+    }
 }
 
-pub fn open_from_stream<R: Read + Seek + Send + 'static>(stream: R) -> file::Result<Project> {
-    let mut archive = match ZipArchive::new(stream) {
-        Ok(val) => val,
+fn open_archive<R: Read + Seek>(stream: R) -> file::Result<ZipArchive<R>> {
+    match ZipArchive::new(stream) {
+        Ok(val) => Ok(val),
 
         Err(err) => match err {
-            zip::result::ZipError::InvalidArchive(_) => {
-                return Err(file::Error::InvalidFormat);
-            }
+            zip::result::ZipError::InvalidArchive(_) => Err(file::Error::InvalidFormat),
 
-            zip::result::ZipError::UnsupportedArchive(_) => {
-                return Err(file::Error::UnsupportedFormat);
-            }
+            zip::result::ZipError::UnsupportedArchive(_) => Err(file::Error::UnsupportedFormat),
 
-            zip::result::ZipError::InvalidPassword => {
-                return Err(file::Error::PasswordNotCorrect);
-            }
+            zip::result::ZipError::InvalidPassword => Err(file::Error::PasswordNotCorrect),
 
-            _ => {
-                return Err(file::Error::Undefined);
-            }
+            _ => Err(file::Error::Undefined),
         },
-    };
+    }
+}
 
-    let manifest = {
+fn read<R: Read + Seek>(archive: &mut ZipArchive<R>) -> file::Result<(Manifest, Value)> {
+    let manifest: Manifest = {
         let file = "cangyan.json";
 
         let stream = match archive.by_name(file) {
@@ -93,32 +125,35 @@ pub fn open_from_stream<R: Read + Seek + Send + 'static>(stream: R) -> file::Res
     };
 
     let value = {
-        let file = "project.json";
-
-        let stream = match archive.by_name(file) {
+        let encoding = iter::once(manifest.encoding())
+            .chain(Encoding::ALL)
+            .find(|encoding| archive.index_for_name(encoding.file()).is_some())
+            .ok_or(file::Error::FileNotFound {
+                file: manifest.encoding().file().to_string(),
+            })?;
+
+        let stream = match archive.by_name(encoding.file()) {
             Ok(val) => val,
 
             Err(_) => {
                 return Err(file::Error::FileNotFound {
-                    file: file.to_string(),
+                    file: encoding.file().to_string(),
                 });
             }
         };
 
-        match serde_json::from_reader(stream) {
-            Ok(val) => val,
-
-            Err(err) => {
-                return Err(file::Error::ParseFailure {
-                    file: file.to_string(),
-                    line: err.line(),
-                    column: err.column(),
-                });
-            }
-        }
+        encoding.decode(stream)?
     };
 
-    let reader = Reader::new(&manifest, &value, archive);
+    Ok((manifest, value))
+}
+
+fn decode(
+    manifest: &Manifest,
+    value: &Value,
+    source: Arc<dyn AssetSource>,
+) -> file::Result<Project> {
+    let reader = Reader::new(manifest, value, source);
 
     Ok(Project::decode(&reader)?)
 }
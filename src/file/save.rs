@@ -5,9 +5,19 @@ use crate::codec::AssetSnap;
 use crate::codec::Writer;
 use crate::file;
 use crate::file::Manifest;
+#[cfg(feature = "rayon")]
+use rayon::iter::IntoParallelRefIterator;
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::Cursor;
 use std::io::Write;
 use std::path::Path;
 use tempfile::NamedTempFile;
+use zip::DateTime;
+use zip::ZipArchive;
 use zip::ZipWriter;
 use zip::write::FileOptions;
 
@@ -55,6 +65,22 @@ pub fn save_to_stream(
 
     let (assets, value) = writer.end();
 
+    let assets = assets.lock().map_err(|_| file::Error::Undefined)?;
+
+    let compressed = compress(&assets, options())?;
+
+    archive(stream, manifest, &value, &assets, compressed)
+}
+
+fn archive(
+    stream: &mut dyn codec::Stream,
+    manifest: &Manifest,
+    value: &Value,
+    assets: &BTreeMap<String, AssetSnap>,
+    mut compressed: HashMap<&str, Vec<u8>>,
+) -> file::Result<()> {
+    let encoding = manifest.encoding();
+
     let manifest =
         serde_json::to_string_pretty(&manifest).map_err(|err| file::Error::ParseFailure {
             file: String::new(),
@@ -62,35 +88,38 @@ pub fn save_to_stream(
             column: err.column(),
         })?;
 
-    let project =
-        serde_json::to_string_pretty(&value).map_err(|err| file::Error::ParseFailure {
-            file: String::new(),
-            line: err.line(),
-            column: err.column(),
-        })?;
+    let project = encoding.encode(value)?;
 
     let mut writer = ZipWriter::new(stream);
 
     writer.set_comment(crate::file::IDENTIFIER.to_owned().into_boxed_str());
 
-    let options = FileOptions::<()>::default().compression_method(zip::CompressionMethod::Deflated);
+    let options = options();
 
     writer.start_file("cangyan.json", options)?;
     writer.write_all(manifest.as_bytes())?;
 
-    writer.start_file("project.json", options)?;
-    writer.write_all(project.as_bytes())?;
+    writer.start_file(encoding.file(), options)?;
+    writer.write_all(&project)?;
 
-    for (path, snap) in assets.lock().map_err(|_| file::Error::Undefined)?.iter() {
+    for (path, snap) in assets.iter() {
         match snap {
-            AssetSnap::Clean(source) => {
-                source.copy(path.as_str(), &mut writer)?;
+            AssetSnap::Clean(source, origin) => {
+                source.copy(origin.as_str(), path.as_str(), &mut writer)?;
             }
 
-            AssetSnap::Dirty(data) => {
-                writer.start_file(path.as_str(), options)?;
-                writer.write_all(data)?;
-            }
+            AssetSnap::Dirty(data) => match compressed.remove(path.as_str()) {
+                Some(entry) => {
+                    let mut archive = ZipArchive::new(Cursor::new(entry))?;
+
+                    writer.raw_copy_file(archive.by_index(0)?)?;
+                }
+
+                None => {
+                    writer.start_file(path.as_str(), options)?;
+                    writer.write_all(data)?;
+                }
+            },
         }
     }
 
@@ -98,3 +127,234 @@ pub fn save_to_stream(
 
     Ok(())
 }
+
+fn options() -> FileOptions<'static, ()> {
+    FileOptions::<()>::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .last_modified_time(DateTime::default())
+}
+
+#[cfg(feature = "rayon")]
+fn compress<'a>(
+    assets: &'a BTreeMap<String, AssetSnap>,
+    options: FileOptions<()>,
+) -> file::Result<HashMap<&'a str, Vec<u8>>> {
+    assets
+        .par_iter()
+        .filter_map(|(path, snap)| match snap {
+            AssetSnap::Dirty(data) => Some((path.as_str(), data)),
+            AssetSnap::Clean(..) => None,
+        })
+        .map(|(path, data)| {
+            let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+            writer.start_file(path, options)?;
+            writer.write_all(data)?;
+
+            Ok((path, writer.finish()?.into_inner()))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn compress<'a>(
+    _: &'a BTreeMap<String, AssetSnap>,
+    _: FileOptions<()>,
+) -> file::Result<HashMap<&'a str, Vec<u8>>> {
+    Ok(HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Asset;
+    use crate::Codec;
+    use crate::Note;
+    use crate::Page;
+    use crate::Project;
+    use crate::Text;
+    use crate::codec::Writer;
+    use crate::file;
+    use crate::file::Encoding;
+    use crate::file::Manifest;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use zip::ZipArchive;
+
+    fn project() -> Project {
+        (1..=8).fold(
+            Project::new()
+                .with_title("Project Title")
+                .with_cover(Asset::new("cover.webp", vec![0; 64])),
+            |project, index| {
+                project.with_page(
+                    Page::new()
+                        .with_image(Asset::new(
+                            format!("pages/page_{}.webp", index),
+                            vec![index; 64],
+                        ))
+                        .with_note(
+                            Note::new()
+                                .with_position(0.5, 0.5)
+                                .with_text(Text::new().with_content("Content")),
+                        ),
+                )
+            },
+        )
+    }
+
+    fn save(project: &Project) -> Vec<u8> {
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        stream.into_inner()
+    }
+
+    fn compare(project: &Project, expected: usize) {
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        if let Err(err) = Codec::encode(project, &mut writer) {
+            panic!("Failed to encode project: {:?}", err);
+        }
+
+        let (assets, value) = writer.end();
+
+        let assets = match assets.lock() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to lock assets: {:?}", err),
+        };
+
+        let compressed = match super::compress(&assets, super::options()) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to compress assets: {:?}", err),
+        };
+
+        assert_eq!(compressed.len(), expected);
+
+        let mut parallel = Cursor::new(Vec::new());
+        let mut serial = Cursor::new(Vec::new());
+
+        if let Err(err) = super::archive(&mut parallel, &manifest, &value, &assets, compressed) {
+            panic!("Failed to write parallel archive: {:?}", err);
+        }
+
+        if let Err(err) = super::archive(&mut serial, &manifest, &value, &assets, HashMap::new()) {
+            panic!("Failed to write serial archive: {:?}", err);
+        }
+
+        assert_eq!(parallel.into_inner(), serial.into_inner());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn compress() {
+        compare(&project(), 9);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn compress_mixed() {
+        let mut project = match file::open_from_stream(Cursor::new(save(&project()))) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        let page = &mut project.pages_mut()[2];
+
+        let mut image = match page.image() {
+            Some(val) => val.clone(),
+            None => panic!("Page image is missing"),
+        };
+
+        image.set_data(vec![0xff; 64]);
+
+        page.set_image(Some(image));
+
+        compare(&project, 1);
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    #[test]
+    fn compress() {
+        compare(&project(), 0);
+    }
+
+    #[test]
+    fn deterministic() {
+        let data = save(&project());
+
+        assert_eq!(data, save(&project()));
+
+        let archive = match ZipArchive::new(Cursor::new(data)) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open archive: {:?}", err),
+        };
+
+        let names = archive.file_names().collect::<Vec<_>>();
+
+        let mut sorted = names[2..].to_vec();
+
+        sorted.sort();
+
+        assert_eq!(names[..2], ["cangyan.json", "project.json"]);
+        assert_eq!(names[2..], sorted);
+    }
+
+    #[test]
+    fn resave() {
+        let data = save(&project());
+
+        let project = match file::open_from_stream(Cursor::new(data.clone())) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        assert_eq!(data, save(&project));
+    }
+
+    #[test]
+    fn encodings() {
+        for encoding in [Encoding::Cbor, Encoding::MessagePack] {
+            let manifest = Manifest::default().with_encoding(encoding);
+
+            let mut stream = Cursor::new(Vec::new());
+
+            if let Err(err) = file::save_to_stream(&mut stream, &manifest, &project()) {
+                panic!("Failed to save {:?}: {:?}", encoding, err);
+            }
+
+            let data = stream.into_inner();
+
+            let archive = match ZipArchive::new(Cursor::new(data.clone())) {
+                Ok(val) => val,
+                Err(err) => panic!("Failed to open archive: {:?}", err),
+            };
+
+            assert!(archive.file_names().any(|name| name == encoding.file()));
+
+            let project = match file::open_from_stream(Cursor::new(data.clone())) {
+                Ok(val) => val,
+                Err(err) => panic!("Failed to open {:?}: {:?}", encoding, err),
+            };
+
+            assert_eq!(project.title(), "Project Title");
+            assert_eq!(project.pages().len(), 8);
+            assert_eq!(
+                project.pages()[0].notes()[0].texts()[0].content(),
+                "Content"
+            );
+
+            let mut stream = Cursor::new(Vec::new());
+
+            if let Err(err) = file::save_to_stream(&mut stream, &manifest, &project) {
+                panic!("Failed to resave {:?}: {:?}", encoding, err);
+            }
+
+            assert_eq!(data, stream.into_inner());
+        }
+    }
+}
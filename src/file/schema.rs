@@ -0,0 +1,25 @@
+use crate::Project;
+use crate::codec::Schema;
+use crate::file;
+use serde_json::Map;
+use serde_json::Value;
+
+pub fn schema(version: u8) -> file::Result<Value> {
+    let mut schema = Map::new();
+
+    schema.insert(
+        "$schema".to_string(),
+        Value::from("https://json-schema.org/draft/2020-12/schema"),
+    );
+
+    schema.insert(
+        "title".to_string(),
+        Value::from(format!("Cangyan project (version {})", version)),
+    );
+
+    if let Value::Object(map) = Project::schema(version)? {
+        schema.extend(map);
+    }
+
+    Ok(Value::Object(schema))
+}
@@ -0,0 +1,519 @@
+use crate::file;
+use serde_json::Value;
+use std::fmt;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    path: String,
+
+    message: String,
+}
+
+impl Violation {
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at `{}`: {}", self.path, self.message)
+    }
+}
+
+pub fn validate(value: &Value, version: u8) -> file::Result<Vec<Violation>> {
+    let schema = file::schema(version)?;
+
+    let mut violations = Vec::new();
+
+    check(&schema, value, "", &mut violations);
+
+    Ok(violations)
+}
+
+fn check(schema: &Value, value: &Value, path: &str, violations: &mut Vec<Violation>) {
+    if let Some(branches) = schema.get("anyOf").and_then(Value::as_array) {
+        let matched = branches.iter().any(|branch| {
+            let mut violations = Vec::new();
+
+            check(branch, value, path, &mut violations);
+
+            violations.is_empty()
+        });
+
+        if !matched {
+            let candidates = branches
+                .iter()
+                .filter(|branch| !accepts_null(branch))
+                .collect::<Vec<_>>();
+
+            let tagged = value
+                .get("type")
+                .and_then(|found| branches.iter().find(|branch| tag(branch) == Some(found)));
+
+            match (tagged, candidates.as_slice()) {
+                (Some(branch), _) => check(branch, value, path, violations),
+
+                (None, [branch]) if !value.is_null() => check(branch, value, path, violations),
+
+                _ => violations.push(Violation {
+                    path: pointer(path),
+                    message: format!(
+                        "expect {}, but found `{}`",
+                        branches
+                            .iter()
+                            .map(describe)
+                            .collect::<Vec<_>>()
+                            .join(" or "),
+                        value
+                    ),
+                }),
+            }
+        }
+
+        return;
+    }
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str)
+        && !matches_type(expected, value)
+    {
+        violations.push(Violation {
+            path: pointer(path),
+            message: format!("expect {}, but found `{}`", describe(schema), value),
+        });
+
+        return;
+    }
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array)
+        && !values.contains(value)
+    {
+        violations.push(Violation {
+            path: pointer(path),
+            message: format!("expect {}, but found `{}`", describe(schema), value),
+        });
+
+        return;
+    }
+
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64)
+        && value.as_f64().is_some_and(|value| value < minimum)
+    {
+        violations.push(Violation {
+            path: pointer(path),
+            message: format!(
+                "expect {} no less than {}, but found `{}`",
+                describe(schema),
+                minimum,
+                value
+            ),
+        });
+
+        return;
+    }
+
+    if let Value::Array(items) = value {
+        let min = schema.get("minItems").and_then(Value::as_u64);
+        let max = schema.get("maxItems").and_then(Value::as_u64);
+
+        let len = items.len() as u64;
+
+        if min.is_some_and(|min| len < min) || max.is_some_and(|max| len > max) {
+            let bounds = match (min, max) {
+                (Some(min), Some(max)) if min == max => format!("exactly {}", min),
+                (Some(min), Some(max)) => format!("between {} and {}", min, max),
+                (Some(min), None) => format!("at least {}", min),
+                (None, Some(max)) => format!("at most {}", max),
+                (None, None) => "any number of".to_string(),
+            };
+
+            violations.push(Violation {
+                path: pointer(path),
+                message: format!(
+                    "expect {} of {} items, but found `{}`",
+                    describe(schema),
+                    bounds,
+                    value
+                ),
+            });
+
+            return;
+        }
+    }
+
+    if let Value::Object(map) = value {
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for field in required.iter().filter_map(Value::as_str) {
+                if !map.contains_key(field) {
+                    violations.push(Violation {
+                        path: pointer(path),
+                        message: format!("required field `{}` is missing", field),
+                    });
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+
+        for (key, value) in map {
+            let path = format!("{}/{}", path, escape(key));
+
+            match properties.and_then(|properties| properties.get(key)) {
+                Some(schema) => check(schema, value, &path, violations),
+
+                None => {
+                    if let Some(schema) = schema.get("additionalProperties")
+                        && schema.is_object()
+                    {
+                        check(schema, value, &path, violations);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Value::Array(items) = value
+        && let Some(schema) = schema.get("items")
+    {
+        for (index, item) in items.iter().enumerate() {
+            check(schema, item, &format!("{}/{}", path, index), violations);
+        }
+    }
+}
+
+fn matches_type(expected: &str, value: &Value) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+
+        _ => true,
+    }
+}
+
+fn accepts_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn tag(schema: &Value) -> Option<&Value> {
+    schema.pointer("/properties/type/enum/0")
+}
+
+fn describe(schema: &Value) -> String {
+    if let Some(tag) = tag(schema).and_then(Value::as_str) {
+        return format!("an object of type `{}`", tag);
+    }
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return format!(
+            "one of {}",
+            values
+                .iter()
+                .map(|value| format!("`{}`", value))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("null") => "null".to_string(),
+        Some("boolean") => "a boolean".to_string(),
+        Some("integer") => "an integer".to_string(),
+        Some("number") => "a number".to_string(),
+        Some("string") => "a string".to_string(),
+        Some("array") => "an array".to_string(),
+        Some("object") => "an object".to_string(),
+
+        _ => "any value".to_string(),
+    }
+}
+
+fn pointer(path: &str) -> String {
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Alignment;
+    use crate::Asset;
+    use crate::Assignment;
+    use crate::Codec;
+    use crate::Comment;
+    use crate::Direction;
+    use crate::Kind;
+    use crate::Member;
+    use crate::Note;
+    use crate::Orientation;
+    use crate::Page;
+    use crate::Project;
+    use crate::Review;
+    use crate::Role;
+    use crate::Shape;
+    use crate::Span;
+    use crate::Stage;
+    use crate::Status;
+    use crate::Style;
+    use crate::Text;
+    use crate::Thread;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use crate::file::validate;
+    use serde_json::json;
+
+    #[test]
+    fn valid() {
+        let value = json!({
+            "cover": null,
+            "title": "Project Title",
+            "overview": "This is an overview.",
+            "pages": [
+                {
+                    "image": "image1.png",
+                    "notes": [
+                        {
+                            "x": 0.5,
+                            "y": 0.25,
+                            "texts": [
+                                {
+                                    "content": "This is a content.",
+                                    "comment": ""
+                                }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        });
+
+        match validate(&value, 0) {
+            Ok(violations) => assert!(violations.is_empty(), "{:?}", violations),
+            Err(err) => panic!("Failed to validate: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let value = json!({
+            "cover": 1,
+            "title": "Project Title",
+            "pages": [
+                {
+                    "image": null,
+                    "notes": [
+                        {
+                            "x": "0.5",
+                            "y": 0.25,
+                            "texts": []
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let violations = match validate(&value, 0) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to validate: {:?}", err),
+        };
+
+        let violations = violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            vec![
+                "at `/`: required field `overview` is missing",
+                "at `/cover`: expect a string, but found `1`",
+                "at `/pages/0/notes/0/x`: expect a number, but found `\"0.5\"`",
+            ]
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        let value = json!({
+            "cover": null,
+            "title": "",
+            "overview": "",
+            "pages": [
+                {
+                    "image": null,
+                    "status": "finished",
+                    "width": -1,
+                    "notes": []
+                }
+            ]
+        });
+
+        let violations = match validate(&value, 0) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to validate: {:?}", err),
+        };
+
+        let violations = violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            vec![
+                "at `/pages/0/status`: expect one of `\"untranslated\"`, `\"translated\"`, `\"proofread\"`, `\"typeset\"`, `\"done\"`, but found `\"finished\"`",
+                "at `/pages/0/width`: expect an integer no less than 0, but found `-1`",
+            ]
+        );
+    }
+
+    #[test]
+    fn tagged() {
+        let value = json!({
+            "cover": null,
+            "title": "",
+            "overview": "",
+            "pages": [
+                {
+                    "image": null,
+                    "notes": [
+                        {
+                            "x": 0.5,
+                            "y": 0.5,
+                            "shape": {
+                                "type": "polygon",
+                                "points": [[0.0], [1.0, 2.0, 3.0]]
+                            },
+                            "texts": []
+                        },
+                        {
+                            "x": 0.5,
+                            "y": 0.5,
+                            "shape": {
+                                "type": "circle"
+                            },
+                            "texts": []
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let violations = match validate(&value, 0) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to validate: {:?}", err),
+        };
+
+        let violations = violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            violations,
+            vec![
+                "at `/pages/0/notes/0/shape/points/0`: expect an array of exactly 2 items, but found `[0.0]`",
+                "at `/pages/0/notes/0/shape/points/1`: expect an array of exactly 2 items, but found `[1.0,2.0,3.0]`",
+                "at `/pages/0/notes/1/shape`: expect an object of type `rectangle` or an object of type `rotated_rectangle` or an object of type `polygon`, but found `{\"type\":\"circle\"}`",
+            ]
+        );
+    }
+
+    #[test]
+    fn encoded() {
+        let mut text = Text::new()
+            .with_spans(vec![
+                Span::ruby("東京", "とうきょう"),
+                Span::line_break(),
+                Span::run("GO!").with_bold(true).with_emphasis(true),
+            ])
+            .with_comment("This is a comment.")
+            .with_language("en")
+            .with_role(Role::Translation)
+            .with_author("translator")
+            .with_review(Review::Approved)
+            .with_remark(Comment::new("Fine.").with_author("proofreader"))
+            .with_overrides(Style::new().with_size(32.0))
+            .with_thread(Thread::new(Comment::new("Pun?")).with_reply(Comment::new("Yes.")))
+            .with_extension("com.example.ocr", "confidence", 0.75);
+
+        text.set_created(Some(1_700_000_000));
+        text.set_modified(Some(1_700_000_060));
+
+        let note = Note::new()
+            .with_position(0.5, 0.5)
+            .with_shape(Shape::polygon([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]))
+            .with_kind(Kind::from("flashback"))
+            .with_style("dialogue")
+            .with_overrides(Style::new().with_orientation(Orientation::Vertical))
+            .with_text(Text::new().with_content("ドカーン").with_role(Role::Source))
+            .with_text(text)
+            .with_thread(Thread::new(Comment::new("Check this.")).with_resolved(true));
+
+        let page = Page::new()
+            .with_image(Asset::new("pages/page_1.png", vec![0; 16]))
+            .with_label("1")
+            .with_status(Status::Proofread)
+            .with_dimensions(800, 1200)
+            .with_spread(1)
+            .with_note(note)
+            .with_note(Note::new().with_shape(Shape::rectangle(0.1, 0.1, 0.2, 0.2)))
+            .with_note(Note::new().with_shape(Shape::rotated_rectangle(0.5, 0.5, 0.2, 0.1, 30.0)))
+            .with_thread(Thread::new(Comment::new("Redraw the sign.")));
+
+        let project = Project::new()
+            .with_title("Project Title")
+            .with_overview("This is an overview.")
+            .with_cover(Asset::new("cover.png", vec![1; 16]))
+            .with_page(page)
+            .with_kind("flashback")
+            .with_direction(Direction::LeftToRight)
+            .with_member(Member::new("translator").with_stage(Stage::Translation))
+            .with_assignment(Assignment::new("translator", Stage::Translation, 0..1).with_due(1))
+            .with_style(
+                "dialogue",
+                Style::new()
+                    .with_font("Wild Words")
+                    .with_color("#000000")
+                    .with_stroke("#ffffff")
+                    .with_stroke_width(2.0)
+                    .with_alignment(Alignment::Center),
+            )
+            .with_extension("com.example.app", "theme", "dark");
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        if let Err(err) = Codec::encode(&project, &mut writer) {
+            panic!("Failed to encode project: {:?}", err);
+        }
+
+        let (_, value) = writer.end();
+
+        match validate(&value, 0) {
+            Ok(violations) => assert!(violations.is_empty(), "{:?}", violations),
+            Err(err) => panic!("Failed to validate: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert!(validate(&json!({}), u8::MAX).is_err());
+    }
+}
@@ -1,13 +1,35 @@
 pub(crate) mod codec;
 
 pub mod file;
+pub mod source;
 
 mod project;
 
 pub(crate) use codec::Codec;
 
+pub use project::Alignment;
 pub use project::Asset;
+pub use project::AssetCache;
+pub use project::Assignment;
+pub use project::Comment;
+pub use project::Direction;
+pub use project::DisplayUnit;
+pub use project::Extensions;
+pub use project::Kind;
+pub use project::Location;
+pub use project::Member;
 pub use project::Note;
+pub use project::Orientation;
 pub use project::Page;
 pub use project::Project;
+pub use project::ReadingOrder;
+pub use project::Remark;
+pub use project::Review;
+pub use project::Role;
+pub use project::Shape;
+pub use project::Span;
+pub use project::Stage;
+pub use project::Status;
+pub use project::Style;
 pub use project::Text;
+pub use project::Thread;
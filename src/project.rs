@@ -1,17 +1,62 @@
 mod asset;
+mod cache;
+mod comment;
+mod extensions;
+mod kind;
 mod note;
+mod order;
 mod page;
+mod probe;
+mod rendition;
+mod review;
+mod role;
+mod session;
+mod shape;
+mod span;
+mod spread;
+mod status;
+mod style;
+mod team;
 mod text;
+mod thread;
 
 pub use asset::Asset;
+pub use cache::AssetCache;
+pub use comment::Comment;
+pub use extensions::Extensions;
+pub use kind::Kind;
 pub use note::Note;
+pub use order::Direction;
+pub use order::ReadingOrder;
 pub use page::Page;
+pub use review::Remark;
+pub use review::Review;
+pub use role::Role;
+pub use shape::Shape;
+pub use span::Span;
+pub use spread::DisplayUnit;
+pub use status::Status;
+pub use style::Alignment;
+pub use style::Orientation;
+pub use style::Style;
+pub use team::Assignment;
+pub use team::Member;
+pub use team::Stage;
 pub use text::Text;
+pub use thread::Location;
+pub use thread::Thread;
+
+pub(crate) use session::Session;
 
 use crate::Codec;
 use crate::codec;
 use crate::codec::Reader;
+use crate::codec::Schema;
 use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 #[derive(Debug, Default)]
 pub struct Project {
@@ -22,6 +67,22 @@ pub struct Project {
     overview: String,
 
     pages: Vec<Page>,
+
+    kinds: Vec<String>,
+
+    reading_order: ReadingOrder,
+
+    roster: Vec<Member>,
+
+    assignments: Vec<Assignment>,
+
+    styles: BTreeMap<String, Style>,
+
+    extensions: Extensions,
+
+    cache: Arc<AssetCache>,
+
+    session: Arc<Session>,
 }
 
 impl Project {
@@ -33,12 +94,16 @@ impl Project {
         self.cover.as_ref()
     }
 
-    pub fn set_cover(&mut self, cover: Option<Asset>) {
+    pub fn set_cover(&mut self, mut cover: Option<Asset>) {
+        if let Some(cover) = &mut cover {
+            cover.attach(&self.cache);
+        }
+
         self.cover = cover;
     }
 
     pub fn with_cover(mut self, cover: Asset) -> Self {
-        self.cover = Some(cover);
+        self.set_cover(Some(cover));
 
         self
     }
@@ -87,15 +152,360 @@ impl Project {
         &self.pages
     }
 
-    pub fn pages_mut(&mut self) -> &mut Vec<Page> {
+    pub fn pages_mut(&mut self) -> &mut [Page] {
         &mut self.pages
     }
 
-    pub fn with_page(mut self, page: Page) -> Self {
+    pub fn with_page(mut self, mut page: Page) -> Self {
+        page.attach(&self.session, &self.cache);
+
         self.pages.push(page);
 
         self
     }
+
+    pub fn insert_page(&mut self, index: usize, mut page: Page) {
+        page.attach(&self.session, &self.cache);
+
+        self.pages.insert(index, page);
+
+        for assignment in &mut self.assignments {
+            assignment.insert_page(index);
+        }
+    }
+
+    pub fn remove_page(&mut self, index: usize) -> Page {
+        let page = self.pages.remove(index);
+
+        for assignment in &mut self.assignments {
+            assignment.remove_page(index);
+        }
+
+        page
+    }
+
+    pub fn identity(&self) -> Option<String> {
+        self.session.identity()
+    }
+
+    pub fn set_identity<I>(&mut self, identity: Option<I>)
+    where
+        I: ToString,
+    {
+        self.session
+            .set_identity(identity.map(|identity| identity.to_string()));
+    }
+
+    pub fn with_identity<I>(mut self, identity: I) -> Self
+    where
+        I: ToString,
+    {
+        self.set_identity(Some(identity));
+
+        self
+    }
+
+    pub fn reading_order(&self) -> &ReadingOrder {
+        &self.reading_order
+    }
+
+    pub fn set_reading_order(&mut self, reading_order: ReadingOrder) {
+        self.reading_order = reading_order;
+    }
+
+    pub fn with_reading_order(mut self, reading_order: ReadingOrder) -> Self {
+        self.reading_order = reading_order;
+
+        self
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.reading_order.direction()
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.reading_order.set_direction(direction);
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.reading_order.set_direction(direction);
+
+        self
+    }
+
+    pub fn display_units(&self) -> Vec<DisplayUnit<'_>> {
+        spread::units(&self.pages, self.direction())
+    }
+
+    pub fn sort_notes(&mut self) {
+        for page in &mut self.pages {
+            page.sort_notes(&self.reading_order);
+        }
+    }
+
+    pub fn kinds(&self) -> &Vec<String> {
+        &self.kinds
+    }
+
+    pub fn declare_kind<K>(&mut self, kind: K)
+    where
+        K: ToString,
+    {
+        let kind = kind.to_string();
+
+        if !self.kinds.contains(&kind) {
+            self.kinds.push(kind);
+        }
+    }
+
+    pub fn with_kind<K>(mut self, kind: K) -> Self
+    where
+        K: ToString,
+    {
+        self.declare_kind(kind);
+
+        self
+    }
+
+    pub fn is_declared(&self, kind: &Kind) -> bool {
+        kind.is_builtin() || self.kinds.iter().any(|name| name == kind.as_str())
+    }
+
+    pub fn notes_of_kind<'a>(&'a self, kind: &'a Kind) -> impl Iterator<Item = &'a Note> {
+        self.pages
+            .iter()
+            .flat_map(move |page| page.notes_of_kind(kind))
+    }
+
+    pub fn styles(&self) -> &BTreeMap<String, Style> {
+        &self.styles
+    }
+
+    pub fn styles_mut(&mut self) -> &mut BTreeMap<String, Style> {
+        &mut self.styles
+    }
+
+    pub fn style(&self, name: &str) -> Option<&Style> {
+        self.styles.get(name)
+    }
+
+    pub fn define_style<N>(&mut self, name: N, style: Style) -> Option<Style>
+    where
+        N: ToString,
+    {
+        self.styles.insert(name.to_string(), style)
+    }
+
+    pub fn with_style<N>(mut self, name: N, style: Style) -> Self
+    where
+        N: ToString,
+    {
+        self.styles.insert(name.to_string(), style);
+
+        self
+    }
+
+    pub fn note_style(&self, note: &Note) -> Style {
+        note.style()
+            .and_then(|name| self.styles.get(name))
+            .or_else(|| note.kind().and_then(|kind| self.styles.get(kind.as_str())))
+            .cloned()
+            .unwrap_or_default()
+            .merge(note.overrides())
+    }
+
+    pub fn text_style(&self, note: &Note, text: &Text) -> Style {
+        self.note_style(note).merge(text.overrides())
+    }
+
+    pub fn comment<C>(&self, content: C) -> Comment
+    where
+        C: ToString,
+    {
+        let mut comment = Comment::new(content);
+
+        if let Some(identity) = self.identity() {
+            comment.set_author(identity);
+            comment.set_created(Some(session::now()));
+        }
+
+        comment
+    }
+
+    pub fn threads(&self) -> Vec<(Location, &Thread)> {
+        let mut threads = Vec::new();
+
+        for (p, page) in self.pages.iter().enumerate() {
+            let location = Location::new(p, None, None);
+
+            threads.extend(page.threads().iter().map(|thread| (location, thread)));
+
+            for (n, note) in page.notes().iter().enumerate() {
+                let location = Location::new(p, Some(n), None);
+
+                threads.extend(note.threads().iter().map(|thread| (location, thread)));
+
+                for (t, text) in note.texts().iter().enumerate() {
+                    let location = Location::new(p, Some(n), Some(t));
+
+                    threads.extend(text.threads().iter().map(|thread| (location, thread)));
+                }
+            }
+        }
+
+        threads
+    }
+
+    pub fn unresolved_threads(&self) -> Vec<(Location, &Thread)> {
+        self.threads()
+            .into_iter()
+            .filter(|(_, thread)| !thread.is_resolved())
+            .collect()
+    }
+
+    pub fn texts(&self) -> impl Iterator<Item = &Text> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.notes())
+            .flat_map(|note| note.texts())
+    }
+
+    pub fn texts_with_review(&self, review: Review) -> impl Iterator<Item = &Text> {
+        self.texts().filter(move |text| text.review() == review)
+    }
+
+    pub fn pending_review(&self) -> impl Iterator<Item = &Text> {
+        self.texts_with_review(Review::Pending)
+    }
+
+    pub fn rejected(&self) -> impl Iterator<Item = &Text> {
+        self.texts_with_review(Review::Rejected)
+    }
+
+    pub fn roster(&self) -> &Vec<Member> {
+        &self.roster
+    }
+
+    pub fn roster_mut(&mut self) -> &mut Vec<Member> {
+        &mut self.roster
+    }
+
+    pub fn with_member(mut self, member: Member) -> Self {
+        self.roster.push(member);
+
+        self
+    }
+
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        self.roster.iter().find(|member| member.name() == name)
+    }
+
+    pub fn assignments(&self) -> &Vec<Assignment> {
+        &self.assignments
+    }
+
+    pub fn assignments_mut(&mut self) -> &mut Vec<Assignment> {
+        &mut self.assignments
+    }
+
+    pub fn with_assignment(mut self, assignment: Assignment) -> Self {
+        self.assignments.push(assignment);
+
+        self
+    }
+
+    pub fn assignments_for<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Assignment> {
+        self.assignments
+            .iter()
+            .filter(move |assignment| assignment.member() == name)
+    }
+
+    pub fn pages_assigned_to(&self, name: &str) -> Vec<usize> {
+        self.collect_pages(name, |_, _| true)
+    }
+
+    pub fn open_pages_for(&self, name: &str) -> Vec<usize> {
+        self.collect_pages(name, |page, assignment| {
+            page.status() < assignment.stage().target()
+        })
+    }
+
+    pub fn overdue(&self, now: u64) -> Vec<&Assignment> {
+        self.assignments
+            .iter()
+            .filter(|assignment| assignment.due().is_some_and(|due| due < now))
+            .filter(|assignment| {
+                assignment
+                    .pages()
+                    .filter_map(|index| self.pages.get(index))
+                    .any(|page| page.status() < assignment.stage().target())
+            })
+            .collect()
+    }
+
+    pub fn pages_with_status(&self, status: Status) -> impl Iterator<Item = &Page> {
+        self.pages
+            .iter()
+            .filter(move |page| page.status() == status)
+    }
+
+    pub fn progress(&self, status: Status) -> f32 {
+        if self.pages.is_empty() {
+            return 0.0;
+        }
+
+        let reached = self
+            .pages
+            .iter()
+            .filter(|page| page.status() >= status)
+            .count();
+
+        reached as f32 / self.pages.len() as f32
+    }
+
+    pub fn cache(&self) -> &AssetCache {
+        &self.cache
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    pub fn with_extension<N, K, V>(mut self, namespace: N, key: K, value: V) -> Self
+    where
+        N: ToString,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.extensions.insert(namespace, key, value);
+
+        self
+    }
+
+    fn collect_pages<F>(&self, name: &str, filter: F) -> Vec<usize>
+    where
+        F: Fn(&Page, &Assignment) -> bool,
+    {
+        let mut pages = self
+            .assignments_for(name)
+            .flat_map(|assignment| {
+                assignment.pages().filter(|index| {
+                    self.pages
+                        .get(*index)
+                        .is_some_and(|page| filter(page, assignment))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        pages.sort_unstable();
+        pages.dedup();
+
+        pages
+    }
 }
 
 impl Codec for Project {
@@ -104,12 +514,40 @@ impl Codec for Project {
             0 => {
                 writer.field("cover", &self.cover)?;
 
+                if let Some(cover) = &self.cover {
+                    cover.encode_renditions(writer)?;
+                }
+
                 writer.field("title", &self.title)?;
 
                 writer.field("overview", &self.overview)?;
 
                 writer.field("pages", &self.pages)?;
 
+                if !self.kinds.is_empty() {
+                    writer.field("kinds", &self.kinds)?;
+                }
+
+                if self.reading_order != ReadingOrder::default() {
+                    writer.field("reading_order", &self.reading_order)?;
+                }
+
+                if !self.roster.is_empty() {
+                    writer.field("roster", &self.roster)?;
+                }
+
+                if !self.assignments.is_empty() {
+                    writer.field("assignments", &self.assignments)?;
+                }
+
+                if !self.styles.is_empty() {
+                    writer.field("styles", &self.styles)?;
+                }
+
+                if !self.extensions.is_empty() {
+                    writer.field("extensions", &self.extensions)?;
+                }
+
                 Ok(())
             }
 
@@ -127,6 +565,22 @@ impl Codec for Project {
                 overview: reader.field("overview")?,
 
                 pages: reader.field("pages")?,
+
+                kinds: reader.field_or_default("kinds")?,
+
+                reading_order: reader.field_or_default("reading_order")?,
+
+                roster: reader.field_or_default("roster")?,
+
+                assignments: reader.field_or_default("assignments")?,
+
+                styles: reader.field_or_default("styles")?,
+
+                extensions: reader.field_or_default("extensions")?,
+
+                cache: reader.cache(),
+
+                session: reader.session(),
             }),
 
             version => Err(codec::Error::UnsupportedVersion { version }),
@@ -134,14 +588,57 @@ impl Codec for Project {
     }
 }
 
+impl Schema for Project {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "cover": Option::<Asset>::schema(version)?,
+                    "title": String::schema(version)?,
+                    "overview": String::schema(version)?,
+                    "pages": Vec::<Page>::schema(version)?,
+                    "kinds": Vec::<String>::schema(version)?,
+                    "reading_order": ReadingOrder::schema(version)?,
+                    "roster": Vec::<Member>::schema(version)?,
+                    "assignments": Vec::<Assignment>::schema(version)?,
+                    "styles": BTreeMap::<String, Style>::schema(version)?,
+
+                    "extensions": Extensions::schema(version)?,
+                },
+                "required": ["cover", "title", "overview", "pages"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Asset;
+    use crate::AssetCache;
+    use crate::Assignment;
     use crate::Codec;
+    use crate::Comment;
+    use crate::Direction;
+    use crate::Kind;
+    use crate::Location;
+    use crate::Member;
+    use crate::Note;
+    use crate::Orientation;
     use crate::Page;
     use crate::Project;
+    use crate::ReadingOrder;
+    use crate::Review;
+    use crate::Stage;
+    use crate::Status;
+    use crate::Style;
+    use crate::Text;
+    use crate::Thread;
     use crate::codec::Writer;
     use crate::file::Manifest;
+    use tempfile::TempDir;
 
     #[test]
     fn new() {
@@ -214,6 +711,375 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cache() {
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        let file = root.path().join("page.png");
+
+        if let Err(err) = std::fs::write(&file, [0; 64]) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        let mut project = Project::new()
+            .with_cover(Asset::from_file("cover.png", &file))
+            .with_page(Page::new().with_image(Asset::from_file("pages/page_1.png", &file)));
+
+        for asset in [project.cover(), project.pages()[0].image()] {
+            match asset.map(Asset::bytes) {
+                Some(Ok(data)) => assert_eq!(data.len(), 64),
+                Some(Err(err)) => panic!("Failed to load asset: {:?}", err),
+                None => panic!("Asset is missing"),
+            }
+        }
+
+        assert_eq!(project.cache().len(), 2);
+
+        project.cache().set_budget(0);
+
+        assert!(project.cache().is_empty());
+
+        project.cache().set_budget(AssetCache::DEFAULT_BUDGET);
+
+        project.pages_mut()[0].set_image(Some(Asset::from_file("pages/page_2.png", &file)));
+
+        match project.pages()[0].image().map(Asset::bytes) {
+            Some(Ok(data)) => assert_eq!(data.len(), 64),
+            Some(Err(err)) => panic!("Failed to load asset: {:?}", err),
+            None => panic!("Asset is missing"),
+        }
+
+        assert_eq!(project.cache().len(), 1);
+    }
+
+    #[test]
+    fn progress() {
+        let project = Project::new()
+            .with_page(Page::new().with_status(Status::Done))
+            .with_page(Page::new().with_status(Status::Proofread))
+            .with_page(Page::new().with_status(Status::Translated))
+            .with_page(Page::new());
+
+        assert_eq!(project.pages_with_status(Status::Untranslated).count(), 1);
+
+        assert_eq!(project.progress(Status::Translated), 0.75);
+        assert_eq!(project.progress(Status::Done), 0.25);
+
+        assert_eq!(Project::new().progress(Status::Done), 0.0);
+    }
+
+    #[test]
+    fn kinds() {
+        let project = Project::new()
+            .with_kind("flashback")
+            .with_page(
+                Page::new()
+                    .with_note(Note::new().with_kind(Kind::Sfx))
+                    .with_note(Note::new().with_kind("flashback"))
+                    .with_note(Note::new()),
+            )
+            .with_page(Page::new().with_note(Note::new().with_kind("sfx")));
+
+        assert_eq!(project.notes_of_kind(&Kind::Sfx).count(), 2);
+        assert_eq!(project.notes_of_kind(&Kind::from("flashback")).count(), 1);
+
+        assert!(project.is_declared(&Kind::Aside));
+        assert!(project.is_declared(&Kind::from("flashback")));
+        assert!(!project.is_declared(&Kind::from("memo")));
+    }
+
+    #[test]
+    fn sort_notes() {
+        let mut project = Project::new()
+            .with_reading_order(ReadingOrder::new(Direction::LeftToRight))
+            .with_page(
+                Page::new()
+                    .with_note(Note::new().with_position(0.8, 0.1))
+                    .with_note(Note::new().with_position(0.2, 0.1)),
+            );
+
+        project.sort_notes();
+
+        let xs = project.pages()[0]
+            .notes()
+            .iter()
+            .map(|note| note.x())
+            .collect::<Vec<_>>();
+
+        assert_eq!(xs, vec![0.2, 0.8]);
+    }
+
+    #[test]
+    fn display_units() {
+        let project = Project::new()
+            .with_direction(Direction::RightToLeft)
+            .with_page(Page::new().with_label("1"))
+            .with_page(Page::new().with_label("2").with_spread(0))
+            .with_page(Page::new().with_label("3").with_spread(0))
+            .with_page(Page::new().with_label("4").with_spread(1))
+            .with_page(Page::new().with_label("5"));
+
+        let units = project.display_units();
+
+        assert_eq!(units.len(), 4);
+
+        assert!(!units[0].is_spread());
+        assert!(units[1].is_spread());
+        assert!(!units[2].is_spread());
+
+        assert_eq!(units[1].index(), 1);
+        assert_eq!(units[3].index(), 4);
+
+        let layout = units[1]
+            .layout()
+            .iter()
+            .map(|page| page.label())
+            .collect::<Vec<_>>();
+
+        assert_eq!(layout, vec!["3", "2"]);
+    }
+
+    #[test]
+    fn identity() {
+        let mut project = Project::new()
+            .with_page(Page::new().with_note(Note::new().with_text(Text::new())))
+            .with_identity("translator");
+
+        let text = &mut project.pages_mut()[0].notes_mut()[0].texts_mut()[0];
+
+        assert!(text.author().is_empty());
+        assert!(text.created().is_none());
+        assert!(text.modified().is_none());
+
+        text.set_content("This is a content.");
+
+        assert_eq!(text.author(), "translator");
+        assert!(text.created().is_some());
+        assert!(text.modified().is_some());
+
+        project.set_identity(Some("proofreader"));
+
+        let text = &mut project.pages_mut()[0].notes_mut()[0].texts_mut()[0];
+
+        text.set_content("This is a revised content.");
+
+        assert_eq!(text.author(), "translator");
+
+        let mut text = Text::new();
+
+        text.set_content("This is a content.");
+
+        assert!(text.author().is_empty());
+        assert!(text.modified().is_none());
+    }
+
+    #[test]
+    fn identity_inserted() {
+        let mut project = Project::new()
+            .with_page(Page::new())
+            .with_identity("translator");
+
+        project.pages_mut()[0]
+            .notes_mut()
+            .push(Note::new().with_text(Text::new()));
+
+        let text = &mut project.pages_mut()[0].notes_mut()[0].texts_mut()[0];
+
+        text.set_content("This is a content.");
+
+        assert_eq!(text.author(), "translator");
+        assert!(text.created().is_some());
+
+        let remark = text.add_remark("Too literal.");
+
+        assert_eq!(remark.author(), "translator");
+
+        project.pages_mut()[0].notes_mut()[0]
+            .texts_mut()
+            .push(Text::new());
+
+        let text = &mut project.pages_mut()[0].notes_mut()[0].texts_mut()[1];
+
+        text.set_content("This is another content.");
+
+        assert_eq!(text.author(), "translator");
+
+        let mut copy = text.clone();
+
+        copy.set_author("");
+        copy.set_modified(None);
+
+        copy.set_content("This is a copied content.");
+
+        assert!(copy.author().is_empty());
+        assert!(copy.modified().is_none());
+    }
+
+    #[test]
+    fn review() {
+        let mut project = Project::new()
+            .with_page(
+                Page::new().with_note(
+                    Note::new()
+                        .with_text(Text::new().with_review(Review::Approved))
+                        .with_text(Text::new().with_review(Review::Rejected))
+                        .with_text(Text::new()),
+                ),
+            )
+            .with_identity("proofreader");
+
+        assert_eq!(project.pending_review().count(), 1);
+        assert_eq!(project.rejected().count(), 1);
+        assert_eq!(project.texts_with_review(Review::NeedsChanges).count(), 0);
+
+        let text = &mut project.pages_mut()[0].notes_mut()[0].texts_mut()[2];
+
+        text.set_review(Review::NeedsChanges);
+
+        let remark = text.add_remark("Too literal.");
+
+        assert_eq!(remark.author(), "proofreader");
+        assert!(remark.created().is_some());
+
+        assert_eq!(project.texts_with_review(Review::NeedsChanges).count(), 1);
+        assert_eq!(project.pending_review().count(), 0);
+    }
+
+    #[test]
+    fn threads() {
+        let project = Project::new().with_identity("typesetter");
+
+        let comment = project.comment("Does this fit the bubble?");
+
+        assert_eq!(comment.author(), "typesetter");
+        assert!(comment.created().is_some());
+
+        let project = project.with_page(
+            Page::new()
+                .with_thread(Thread::new(Comment::new("Missing SFX.")).with_resolved(true))
+                .with_note(
+                    Note::new()
+                        .with_thread(Thread::new(comment))
+                        .with_text(Text::new().with_thread(Thread::new(Comment::new("Typo?")))),
+                ),
+        );
+
+        assert_eq!(project.threads().len(), 3);
+
+        let unresolved = project.unresolved_threads();
+
+        assert_eq!(unresolved.len(), 2);
+
+        assert_eq!(unresolved[0].0, Location::new(0, Some(0), None));
+        assert_eq!(unresolved[1].0.text(), Some(0));
+        assert_eq!(unresolved[1].1.comment().content(), "Typo?");
+    }
+
+    #[test]
+    fn assignments() {
+        let project = Project::new()
+            .with_member(Member::new("alice").with_stage(Stage::Translation))
+            .with_member(Member::new("bob").with_stage(Stage::Proofreading))
+            .with_page(Page::new().with_status(Status::Translated))
+            .with_page(Page::new())
+            .with_page(Page::new())
+            .with_assignment(Assignment::new("alice", Stage::Translation, 0..2).with_due(100))
+            .with_assignment(Assignment::new("alice", Stage::Translation, 1..3))
+            .with_assignment(Assignment::new("bob", Stage::Proofreading, 0..1).with_due(100));
+
+        assert!(project.member("alice").is_some());
+        assert!(project.member("carol").is_none());
+
+        assert_eq!(project.assignments_for("alice").count(), 2);
+
+        assert_eq!(project.pages_assigned_to("alice"), vec![0, 1, 2]);
+        assert_eq!(project.open_pages_for("alice"), vec![1, 2]);
+        assert_eq!(project.open_pages_for("bob"), vec![0]);
+
+        assert_eq!(project.overdue(200).len(), 2);
+        assert!(project.overdue(50).is_empty());
+
+        let project = project
+            .with_assignment(Assignment::new("carol", Stage::Translation, 2..8).with_due(100));
+
+        assert_eq!(project.overdue(200).len(), 3);
+    }
+
+    #[test]
+    fn reindex() {
+        let mut project = Project::new()
+            .with_page(Page::new().with_label("1"))
+            .with_page(Page::new().with_label("2"))
+            .with_page(Page::new().with_label("3"))
+            .with_assignment(Assignment::new("alice", Stage::Translation, 1..3))
+            .with_assignment(Assignment::new("bob", Stage::Proofreading, 0..1));
+
+        project.insert_page(1, Page::new().with_label("1a"));
+
+        assert_eq!(project.pages()[1].label(), "1a");
+        assert_eq!(project.assignments()[0].pages(), 2..4);
+        assert_eq!(project.assignments()[1].pages(), 0..1);
+
+        project.insert_page(3, Page::new().with_label("2a"));
+
+        assert_eq!(project.assignments()[0].pages(), 2..5);
+
+        assert_eq!(project.remove_page(0).label(), "1");
+
+        assert_eq!(project.assignments()[0].pages(), 1..4);
+        assert_eq!(project.assignments()[1].pages(), 0..0);
+
+        assert_eq!(project.remove_page(2).label(), "2a");
+
+        assert_eq!(project.assignments()[0].pages(), 1..3);
+        assert_eq!(project.pages_assigned_to("alice"), vec![1, 2]);
+    }
+
+    #[test]
+    fn styles() {
+        let project = Project::new()
+            .with_style(
+                "dialogue",
+                Style::new().with_font("Wild Words").with_size(24.0),
+            )
+            .with_style("shout", Style::new().with_font("Badaboom").with_size(40.0));
+
+        let note = Note::new().with_kind(Kind::Dialogue);
+
+        let style = project.note_style(&note);
+
+        assert_eq!(style.font(), Some("Wild Words"));
+        assert_eq!(style.size(), Some(24.0));
+
+        let note = Note::new()
+            .with_kind(Kind::Dialogue)
+            .with_style("shout")
+            .with_overrides(Style::new().with_orientation(Orientation::Vertical));
+
+        let text = Text::new().with_overrides(Style::new().with_size(32.0));
+
+        let style = project.text_style(&note, &text);
+
+        assert_eq!(style.font(), Some("Badaboom"));
+        assert_eq!(style.size(), Some(32.0));
+        assert_eq!(style.orientation(), Some(Orientation::Vertical));
+
+        assert!(project.note_style(&Note::new()).is_empty());
+
+        let note = Note::new().with_kind(Kind::Dialogue).with_style("whisper");
+
+        assert_eq!(project.note_style(&note).font(), Some("Wild Words"));
+
+        assert!(
+            project
+                .note_style(&Note::new().with_style("whisper"))
+                .is_empty()
+        );
+    }
+
     #[test]
     fn encode() {
         let page1 = Page::new().with_image(Asset::new("image1.png", Vec::new()));
@@ -1,42 +1,116 @@
 use crate::Codec;
 use crate::codec;
+use crate::codec::AssetSize;
 use crate::codec::AssetSnap;
-use crate::codec::EmptySource;
 use crate::codec::Reader;
+use crate::codec::Schema;
 use crate::codec::Writer;
+use crate::project::AssetCache;
+use crate::project::probe;
+use crate::project::rendition;
+use crate::source::EmptySource;
+use crate::source::FileSource;
+use serde_json::Value;
+use serde_json::json;
+use sha2::Digest;
+use sha2::Sha256;
 use std::fmt;
 use std::fmt::Debug;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::path::Path;
 use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
 
 pub struct Asset {
+    id: u64,
+
     path: String,
 
+    origin: String,
+
     source: Arc<dyn codec::AssetSource>,
 
-    data: RwLock<Option<Vec<u8>>>,
+    cache: Option<Arc<AssetCache>>,
+
+    data: Option<Arc<[u8]>>,
 
     track: Track,
+
+    probe: Probe,
 }
 
+#[derive(Default, Clone)]
+struct Probe {
+    mime: OnceLock<&'static str>,
+
+    dimensions: OnceLock<Option<(u32, u32)>>,
+
+    hash: OnceLock<String>,
+
+    renditions: [OnceLock<Option<Arc<[u8]>>>; Asset::RENDITIONS.len()],
+}
+
+#[derive(Clone, Copy)]
 enum Track {
     Clean,
     Dirty,
 }
 
 impl Asset {
+    pub const THUMBNAIL: u32 = 256;
+
+    pub const RENDITIONS: [u32; 2] = [Asset::THUMBNAIL, 1024];
+
     pub fn new<P>(path: P, data: Vec<u8>) -> Self
     where
         P: ToString,
     {
         Asset {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+
             path: path.to_string(),
 
+            origin: path.to_string(),
+
             source: Arc::new(EmptySource),
 
-            data: RwLock::new(Some(data)),
+            cache: None,
+
+            data: Some(Arc::from(data)),
 
             track: Track::Dirty,
+
+            probe: Probe::default(),
+        }
+    }
+
+    pub fn from_file<P, F>(path: P, file: F) -> Self
+    where
+        P: ToString,
+        F: AsRef<Path>,
+    {
+        Asset {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+
+            path: path.to_string(),
+
+            origin: path.to_string(),
+
+            source: Arc::new(FileSource::new(path.to_string(), file)),
+
+            cache: None,
+
+            data: None,
+
+            track: Track::Clean,
+
+            probe: Probe::default(),
         }
     }
 
@@ -44,35 +118,246 @@ impl Asset {
         &self.path
     }
 
+    pub fn rename<P>(&mut self, path: P)
+    where
+        P: ToString,
+    {
+        self.path = path.to_string();
+    }
+
+    pub fn set_data(&mut self, data: Vec<u8>) {
+        if let Some(cache) = &self.cache {
+            cache.remove(self.id);
+        }
+
+        self.data = Some(Arc::from(data));
+
+        self.track = Track::Dirty;
+
+        self.probe = Probe::default();
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        matches!(self.track, Track::Dirty)
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        match self.track {
+            Track::Clean => self
+                .cache
+                .as_ref()
+                .is_some_and(|cache| cache.contains(self.id)),
+
+            Track::Dirty => true,
+        }
+    }
+
     pub fn load(&self) -> codec::Result<Vec<u8>> {
-        if self
+        self.bytes().map(|data| data.to_vec())
+    }
+
+    pub fn bytes(&self) -> codec::Result<Arc<[u8]>> {
+        if let Some(data) = &self.data {
+            return Ok(Arc::clone(data));
+        }
+
+        if let Some(data) = self.cache.as_ref().and_then(|cache| cache.get(self.id)) {
+            return Ok(data);
+        }
+
+        let data: Arc<[u8]> = Arc::from(self.source.load(&self.origin)?);
+
+        if let Some(cache) = &self.cache {
+            cache.insert(self.id, Arc::clone(&data));
+        }
+
+        Ok(data)
+    }
+
+    pub fn open(&self) -> codec::Result<Box<dyn Read + Send>> {
+        match self
             .data
-            .read()
-            .map_err(|_| codec::Error::AssetAccessFailed {
-                path: self.path.to_string(),
-            })?
-            .is_none()
+            .clone()
+            .or_else(|| self.cache.as_ref().and_then(|cache| cache.get(self.id)))
         {
-            let data = self.source.load(&self.path)?;
+            Some(data) => Ok(Box::new(Cursor::new(data))),
 
-            *self
-                .data
-                .write()
-                .map_err(|_| codec::Error::AssetAccessFailed {
-                    path: self.path.to_string(),
-                })? = Some(data);
+            None => self.source.open(&self.origin),
         }
+    }
 
-        self.data
-            .read()
-            .map_err(|_| codec::Error::AssetAccessFailed {
+    pub fn size(&self) -> codec::Result<AssetSize> {
+        match &self.data {
+            Some(data) => Ok(AssetSize::new(None, data.len() as u64)),
+
+            None => self.source.size(&self.origin),
+        }
+    }
+
+    pub fn mime(&self) -> codec::Result<&'static str> {
+        if let Some(mime) = self.probe.mime.get() {
+            return Ok(mime);
+        }
+
+        let header =
+            probe::header(&mut self.open()?).map_err(|_| codec::Error::AssetLoadFailed {
                 path: self.path.to_string(),
-            })?
-            .as_ref()
-            .ok_or(codec::Error::AssetNotFound {
+            })?;
+
+        Ok(self.probe.mime.get_or_init(|| probe::mime(&header)))
+    }
+
+    pub fn dimensions(&self) -> codec::Result<Option<(u32, u32)>> {
+        if let Some(dimensions) = self.probe.dimensions.get() {
+            return Ok(*dimensions);
+        }
+
+        let dimensions =
+            probe::dimensions(&mut self.open()?).map_err(|_| codec::Error::AssetLoadFailed {
                 path: self.path.to_string(),
-            })
-            .cloned()
+            })?;
+
+        Ok(*self.probe.dimensions.get_or_init(|| dimensions))
+    }
+
+    pub fn hash(&self) -> codec::Result<&str> {
+        if let Some(hash) = self.probe.hash.get() {
+            return Ok(hash);
+        }
+
+        let mut hasher = Sha256::new();
+
+        io::copy(&mut self.open()?, &mut hasher).map_err(|_| codec::Error::AssetLoadFailed {
+            path: self.path.to_string(),
+        })?;
+
+        let hash = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        Ok(self.probe.hash.get_or_init(|| hash))
+    }
+
+    pub fn rendition(&self, size: u32) -> Option<Asset> {
+        let index = Asset::RENDITIONS.iter().position(|value| *value == size)?;
+
+        let path = rendition::path(&self.path, size);
+
+        let origin = rendition::path(&self.origin, size);
+
+        if matches!(self.track, Track::Clean) && self.source.contains(&origin) {
+            return Some(Asset {
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+
+                path,
+
+                origin,
+
+                source: Arc::clone(&self.source),
+
+                cache: self.cache.clone(),
+
+                data: None,
+
+                track: Track::Clean,
+
+                probe: Probe::default(),
+            });
+        }
+
+        let data = self.probe.renditions[index]
+            .get_or_init(|| self.render(size))
+            .clone()?;
+
+        Some(Asset {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+
+            path: path.clone(),
+
+            origin: path,
+
+            source: Arc::new(EmptySource),
+
+            cache: None,
+
+            data: Some(data),
+
+            track: Track::Dirty,
+
+            probe: Probe::default(),
+        })
+    }
+
+    pub fn unload(&self) -> bool {
+        match self.track {
+            Track::Clean => self
+                .cache
+                .as_ref()
+                .is_some_and(|cache| cache.remove(self.id)),
+
+            Track::Dirty => false,
+        }
+    }
+
+    pub(crate) fn attach(&mut self, cache: &Arc<AssetCache>) {
+        if self
+            .cache
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, cache))
+        {
+            return;
+        }
+
+        if let Some(current) = self.cache.replace(Arc::clone(cache)) {
+            current.remove(self.id);
+        }
+    }
+
+    pub(crate) fn encode_renditions(&self, writer: &mut Writer) -> codec::Result<()> {
+        for size in Asset::RENDITIONS {
+            if let Some(rendition) = self.rendition(size) {
+                writer.asset(rendition.path.clone(), rendition.snap()?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&self, size: u32) -> Option<Arc<[u8]>> {
+        if !cfg!(feature = "image") {
+            return None;
+        }
+
+        let data = match &self.data {
+            Some(data) => Arc::clone(data),
+
+            None => {
+                let mut data = Vec::new();
+
+                self.open().ok()?.read_to_end(&mut data).ok()?;
+
+                Arc::from(data)
+            }
+        };
+
+        rendition::render(&data, size).map(Arc::from)
+    }
+
+    fn snap(&self) -> codec::Result<AssetSnap> {
+        match self.track {
+            Track::Clean => Ok(AssetSnap::Clean(
+                Arc::clone(&self.source),
+                self.origin.clone(),
+            )),
+
+            Track::Dirty => Ok(AssetSnap::Dirty(self.data.clone().ok_or(
+                codec::Error::AssetNotFound {
+                    path: self.path.to_string(),
+                },
+            )?)),
+        }
     }
 }
 
@@ -82,23 +367,7 @@ impl Codec for Asset {
             0 => {
                 writer.value(self.path.clone());
 
-                writer.asset(
-                    self.path.clone(),
-                    match self.track {
-                        Track::Clean => AssetSnap::Clean(Arc::clone(&self.source)),
-                        Track::Dirty => AssetSnap::Dirty(
-                            self.data
-                                .read()
-                                .map_err(|_| codec::Error::AssetAccessFailed {
-                                    path: self.path.to_string(),
-                                })?
-                                .clone()
-                                .ok_or(codec::Error::AssetNotFound {
-                                    path: self.path.to_string(),
-                                })?,
-                        ),
-                    },
-                );
+                writer.asset(self.path.clone(), self.snap()?)?;
 
                 Ok(())
             }
@@ -109,40 +378,99 @@ impl Codec for Asset {
 
     fn decode(reader: &Reader) -> codec::Result<Self> {
         match reader.manifest().version() {
-            0 => Ok(Asset {
-                path: reader
+            0 => {
+                let path = reader
                     .value()
                     .as_str()
                     .ok_or(codec::Error::MismatchType {
                         expected: "string".to_string(),
                         found: reader.value().to_string(),
                     })?
-                    .to_string(),
+                    .to_string();
 
-                source: reader.asset(),
+                Ok(Asset {
+                    id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
 
-                data: RwLock::new(None),
+                    origin: path.clone(),
 
-                track: Track::Clean,
-            }),
+                    path,
+
+                    source: reader.asset(),
+
+                    cache: Some(reader.cache()),
+
+                    data: None,
+
+                    track: Track::Clean,
+
+                    probe: Probe::default(),
+                })
+            }
 
             version => Err(codec::Error::UnsupportedVersion { version }),
         }
     }
 }
 
+impl Clone for Asset {
+    fn clone(&self) -> Self {
+        Asset {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+
+            path: self.path.clone(),
+
+            origin: self.origin.clone(),
+
+            source: Arc::clone(&self.source),
+
+            cache: self.cache.clone(),
+
+            data: self.data.clone(),
+
+            track: self.track,
+
+            probe: self.probe.clone(),
+        }
+    }
+}
+
+impl Drop for Asset {
+    fn drop(&mut self) {
+        if let Some(cache) = &self.cache {
+            cache.remove(self.id);
+        }
+    }
+}
+
 impl Debug for Asset {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Asset(\"{}\")", self.path))
     }
 }
 
+impl Schema for Asset {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({ "type": "string" })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Asset;
     use crate::Codec;
+    use crate::Project;
+    use crate::codec;
     use crate::codec::Writer;
+    use crate::file;
     use crate::file::Manifest;
+    use std::io::Cursor;
+    use std::io::Read;
+    use std::sync::Arc;
+    use tempfile::TempDir;
 
     #[test]
     fn path() {
@@ -164,6 +492,381 @@ mod tests {
         }
     }
 
+    #[test]
+    fn open() {
+        let asset = Asset::new("asset.png", vec![0, 1, 2, 3]);
+
+        let mut data = Vec::new();
+
+        match asset.open() {
+            Ok(mut stream) => {
+                if let Err(err) = stream.read_to_end(&mut data) {
+                    panic!("Failed to read asset: {:?}", err);
+                }
+            }
+
+            Err(err) => panic!("Failed to open asset: {:?}", err),
+        }
+
+        assert_eq!(data, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn bytes() {
+        let asset = Asset::new("asset.png", vec![0, 1, 2, 3]);
+
+        match (asset.bytes(), asset.bytes()) {
+            (Ok(a), Ok(b)) => assert!(Arc::ptr_eq(&a, &b)),
+
+            _ => panic!("Failed to load asset"),
+        }
+    }
+
+    #[test]
+    fn metadata() {
+        let mut data = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+        data.extend([0, 0, 0, 13]);
+        data.extend(b"IHDR");
+        data.extend(640u32.to_be_bytes());
+        data.extend(480u32.to_be_bytes());
+
+        let asset = Asset::new("asset.png", data);
+
+        assert!(matches!(asset.mime(), Ok("image/png")));
+        assert!(matches!(asset.dimensions(), Ok(Some((640, 480)))));
+
+        match asset.size() {
+            Ok(size) => {
+                assert_eq!(size.compressed(), None);
+                assert_eq!(size.uncompressed(), 24);
+            }
+
+            Err(err) => panic!("Failed to read size: {:?}", err),
+        }
+
+        match Asset::new("asset.bin", Vec::new()).hash() {
+            Ok(hash) => assert_eq!(
+                hash,
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+            ),
+
+            Err(err) => panic!("Failed to hash asset: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn unload() {
+        let project = Project::new().with_cover(Asset::new("cover.png", vec![0, 1, 2, 3]));
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        stream.set_position(0);
+
+        let project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        let cover = match project.cover() {
+            Some(val) => val,
+            None => panic!("Expected cover, found None"),
+        };
+
+        assert!(!cover.is_dirty());
+        assert!(!cover.is_loaded());
+
+        match cover.load() {
+            Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        assert!(cover.is_loaded());
+        assert_eq!(project.cache().size(), 4);
+
+        assert!(cover.unload());
+
+        assert!(!cover.is_loaded());
+        assert!(project.cache().is_empty());
+    }
+
+    #[test]
+    fn set_data() {
+        let mut asset = Asset::new("asset.bin", vec![0, 1, 2, 3]);
+
+        assert!(asset.hash().is_ok());
+
+        asset.set_data(Vec::new());
+
+        assert!(asset.is_dirty());
+
+        match asset.hash() {
+            Ok(hash) => assert_eq!(
+                hash,
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+            ),
+
+            Err(err) => panic!("Failed to hash asset: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn rename() {
+        let project = Project::new().with_cover(Asset::new("cover.png", vec![0, 1, 2, 3]));
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        stream.set_position(0);
+
+        let mut project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        let mut cover = match project.cover() {
+            Some(val) => val.clone(),
+            None => panic!("Expected cover, found None"),
+        };
+
+        cover.rename("images/cover.png");
+
+        assert!(!cover.is_dirty());
+
+        project.set_cover(Some(cover));
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        stream.set_position(0);
+
+        let project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        match project.cover() {
+            Some(cover) => {
+                assert_eq!(cover.path(), "images/cover.png");
+
+                match cover.load() {
+                    Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+                    Err(err) => panic!("Failed to load asset: {:?}", err),
+                }
+            }
+
+            None => panic!("Expected cover, found None"),
+        }
+    }
+
+    #[test]
+    fn clone() {
+        let asset = Asset::new("asset.png", vec![0, 1, 2, 3]);
+
+        let mut clone = asset.clone();
+
+        assert_eq!(clone.path(), "asset.png");
+
+        clone.set_data(vec![4, 5]);
+
+        match (asset.load(), clone.load()) {
+            (Ok(a), Ok(b)) => {
+                assert_eq!(a, vec![0, 1, 2, 3]);
+                assert_eq!(b, vec![4, 5]);
+            }
+
+            _ => panic!("Failed to load asset"),
+        }
+    }
+
+    #[test]
+    fn conflict() {
+        let asset = Asset::new("asset.png", vec![0, 1, 2, 3]);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&asset, &mut writer).is_ok());
+        assert!(Codec::encode(&asset.clone(), &mut writer).is_ok());
+
+        let other = Asset::new("asset.png", vec![4, 5]);
+
+        assert!(matches!(
+            Codec::encode(&other, &mut writer),
+            Err(codec::Error::AssetConflict { .. })
+        ));
+    }
+
+    #[test]
+    fn from_file() {
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        let file = root.path().join("scan.png");
+
+        if let Err(err) = std::fs::write(&file, [0, 1, 2, 3]) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        let asset = Asset::from_file("pages/page_1.png", &file);
+
+        assert_eq!(asset.path(), "pages/page_1.png");
+        assert!(!asset.is_dirty());
+        assert!(!asset.is_loaded());
+
+        let project = Project::new().with_cover(asset);
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        if let Err(err) = std::fs::remove_file(&file) {
+            panic!("Failed to remove file: {:?}", err);
+        }
+
+        stream.set_position(0);
+
+        let project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        match project.cover().map(|cover| cover.load()) {
+            Some(Ok(data)) => assert_eq!(data, vec![0, 1, 2, 3]),
+            Some(Err(err)) => panic!("Failed to load asset: {:?}", err),
+            None => panic!("Expected cover, found None"),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn rendition() {
+        use crate::Page;
+        use image::ImageFormat;
+        use image::RgbImage;
+
+        let mut data = Cursor::new(Vec::new());
+
+        if let Err(err) = RgbImage::new(600, 300).write_to(&mut data, ImageFormat::Png) {
+            panic!("Failed to encode image: {:?}", err);
+        }
+
+        let project = Project::new()
+            .with_page(Page::new().with_image(Asset::new("pages/page_1.png", data.into_inner())));
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        stream.set_position(0);
+
+        let project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        let thumbnail = match project.pages().first().and_then(|page| page.thumbnail()) {
+            Some(val) => val,
+            None => panic!("Expected thumbnail, found None"),
+        };
+
+        assert!(!thumbnail.is_dirty());
+        assert_eq!(thumbnail.path(), "renditions/256/pages/page_1.png.png");
+        assert!(matches!(thumbnail.dimensions(), Ok(Some((256, 128)))));
+
+        match project.pages().first().and_then(|page| page.image()) {
+            Some(image) => {
+                assert!(image.rendition(1024).is_some());
+                assert!(image.rendition(64).is_none());
+            }
+
+            None => panic!("Expected image, found None"),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn rendition_cache() {
+        use crate::Page;
+        use image::ImageFormat;
+        use image::RgbImage;
+
+        let mut data = Cursor::new(Vec::new());
+
+        if let Err(err) = RgbImage::new(600, 300).write_to(&mut data, ImageFormat::Png) {
+            panic!("Failed to encode image: {:?}", err);
+        }
+
+        let data = data.into_inner();
+
+        let mut asset = Asset::new("pages/page_1.png", data.clone());
+
+        let rendered =
+            |asset: &Asset| match asset.rendition(256).and_then(|value| value.data.clone()) {
+                Some(val) => val,
+                None => panic!("Expected rendition, found None"),
+            };
+
+        let first = rendered(&asset);
+
+        assert!(Arc::ptr_eq(&first, &rendered(&asset)));
+
+        asset.set_data(data.clone());
+
+        assert!(!Arc::ptr_eq(&first, &rendered(&asset)));
+
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        let file = root.path().join("scan.png");
+
+        if let Err(err) = std::fs::write(&file, &data) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        let project = Project::new()
+            .with_page(Page::new().with_image(Asset::from_file("pages/page_1.png", &file)));
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = file::save_to_stream(&mut stream, &Manifest::default(), &project) {
+            panic!("Failed to save project: {:?}", err);
+        }
+
+        assert!(project.cache().is_empty());
+
+        stream.set_position(0);
+
+        let project = match file::open_from_stream(stream) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open project: {:?}", err),
+        };
+
+        match project.pages().first().and_then(|page| page.thumbnail()) {
+            Some(thumbnail) => assert!(!thumbnail.is_dirty()),
+            None => panic!("Expected thumbnail, found None"),
+        }
+    }
+
     #[test]
     fn encode() {
         let asset = Asset::new("asset.png", vec![0, 1, 2, 3]);
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub struct AssetCache {
+    state: Mutex<State>,
+}
+
+struct State {
+    budget: usize,
+
+    size: usize,
+
+    tick: u64,
+
+    entries: HashMap<u64, Entry>,
+}
+
+struct Entry {
+    data: Arc<[u8]>,
+
+    tick: u64,
+}
+
+impl AssetCache {
+    pub const DEFAULT_BUDGET: usize = 256 * 1024 * 1024;
+
+    pub fn new(budget: usize) -> Self {
+        AssetCache {
+            state: Mutex::new(State {
+                budget,
+
+                size: 0,
+
+                tick: 0,
+
+                entries: HashMap::new(),
+            }),
+        }
+    }
+
+    pub fn budget(&self) -> usize {
+        self.state.lock().map_or(0, |state| state.budget)
+    }
+
+    pub fn set_budget(&self, budget: usize) {
+        if let Ok(mut state) = self.state.lock() {
+            state.budget = budget;
+
+            state.evict(0);
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.state.lock().map_or(0, |state| state.size)
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().map_or(0, |state| state.entries.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.entries.clear();
+
+            state.size = 0;
+        }
+    }
+
+    pub(crate) fn get(&self, id: u64) -> Option<Arc<[u8]>> {
+        let mut state = self.state.lock().ok()?;
+
+        state.tick += 1;
+
+        let tick = state.tick;
+
+        state.entries.get_mut(&id).map(|entry| {
+            entry.tick = tick;
+
+            Arc::clone(&entry.data)
+        })
+    }
+
+    pub(crate) fn insert(&self, id: u64, data: Arc<[u8]>) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        state.remove(id);
+
+        if data.len() > state.budget {
+            return;
+        }
+
+        state.evict(data.len());
+
+        state.tick += 1;
+
+        let tick = state.tick;
+
+        state.size += data.len();
+
+        state.entries.insert(id, Entry { data, tick });
+    }
+
+    pub(crate) fn contains(&self, id: u64) -> bool {
+        self.state
+            .lock()
+            .is_ok_and(|state| state.entries.contains_key(&id))
+    }
+
+    pub(crate) fn remove(&self, id: u64) -> bool {
+        self.state.lock().is_ok_and(|mut state| state.remove(id))
+    }
+}
+
+impl Default for AssetCache {
+    fn default() -> Self {
+        AssetCache::new(AssetCache::DEFAULT_BUDGET)
+    }
+}
+
+impl Debug for AssetCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetCache")
+            .field("budget", &self.budget())
+            .field("size", &self.size())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl State {
+    fn remove(&mut self, id: u64) -> bool {
+        match self.entries.remove(&id) {
+            Some(entry) => {
+                self.size -= entry.data.len();
+
+                true
+            }
+
+            None => false,
+        }
+    }
+
+    fn evict(&mut self, incoming: usize) {
+        while self.size + incoming > self.budget {
+            let Some(id) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.tick)
+                .map(|(id, _)| *id)
+            else {
+                break;
+            };
+
+            self.remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AssetCache;
+    use std::sync::Arc;
+
+    #[test]
+    fn evict() {
+        let cache = AssetCache::new(8);
+
+        cache.insert(1, Arc::from(vec![0; 4]));
+        cache.insert(2, Arc::from(vec![0; 4]));
+
+        assert!(cache.get(1).is_some());
+
+        cache.insert(3, Arc::from(vec![0; 4]));
+
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(2).is_none());
+        assert!(cache.get(3).is_some());
+
+        assert_eq!(cache.size(), 8);
+    }
+
+    #[test]
+    fn oversized() {
+        let cache = AssetCache::new(8);
+
+        cache.insert(1, Arc::from(vec![0; 16]));
+
+        assert!(cache.get(1).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn set_budget() {
+        let cache = AssetCache::new(8);
+
+        cache.insert(1, Arc::from(vec![0; 4]));
+        cache.insert(2, Arc::from(vec![0; 4]));
+
+        cache.set_budget(4);
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(2).is_some());
+    }
+
+    #[test]
+    fn debug() {
+        let cache = AssetCache::new(8);
+
+        cache.insert(1, Arc::from(vec![7; 4]));
+
+        assert_eq!(
+            format!("{:?}", cache),
+            "AssetCache { budget: 8, size: 4, len: 1 }"
+        );
+    }
+}
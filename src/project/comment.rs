@@ -0,0 +1,126 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Comment {
+    author: String,
+
+    content: String,
+
+    created: Option<u64>,
+}
+
+impl Comment {
+    pub fn new<C>(content: C) -> Self
+    where
+        C: ToString,
+    {
+        Comment {
+            content: content.to_string(),
+
+            ..Self::default()
+        }
+    }
+
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn set_author<A>(&mut self, author: A)
+    where
+        A: ToString,
+    {
+        self.author = author.to_string();
+    }
+
+    pub fn with_author<A>(mut self, author: A) -> Self
+    where
+        A: ToString,
+    {
+        self.author = author.to_string();
+
+        self
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn set_content<C>(&mut self, content: C)
+    where
+        C: ToString,
+    {
+        self.content = content.to_string();
+    }
+
+    pub fn created(&self) -> Option<u64> {
+        self.created
+    }
+
+    pub fn set_created(&mut self, created: Option<u64>) {
+        self.created = created;
+    }
+
+    pub fn with_created(mut self, created: u64) -> Self {
+        self.created = Some(created);
+
+        self
+    }
+}
+
+impl Codec for Comment {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.field("author", &self.author)?;
+
+                writer.field("content", &self.content)?;
+
+                if self.created.is_some() {
+                    writer.field("created", &self.created)?;
+                }
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => Ok(Comment {
+                author: reader.field("author")?,
+
+                content: reader.field("content")?,
+
+                created: reader.field_or_default("created")?,
+            }),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Comment {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "author": String::schema(version)?,
+                    "content": String::schema(version)?,
+                    "created": Option::<u64>::schema(version)?,
+                },
+                "required": ["author", "content"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
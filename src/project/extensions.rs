@@ -0,0 +1,299 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Extensions {
+    namespaces: BTreeMap<String, Map<String, Value>>,
+}
+
+impl Extensions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.namespaces.is_empty()
+    }
+
+    pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+        self.namespaces.keys().map(String::as_str)
+    }
+
+    pub fn namespace(&self, namespace: &str) -> Option<&Map<String, Value>> {
+        self.namespaces.get(namespace)
+    }
+
+    pub fn get(&self, namespace: &str, key: &str) -> Option<&Value> {
+        self.namespaces
+            .get(namespace)
+            .and_then(|entries| entries.get(key))
+    }
+
+    pub fn get_as<T>(&self, namespace: &str, key: &str) -> codec::Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match self.get(namespace, key) {
+            Some(value) => serde_json::from_value(value.clone())
+                .map(Some)
+                .map_err(|err| codec::Error::ExtensionConvertFailed {
+                    namespace: namespace.to_string(),
+                    key: key.to_string(),
+
+                    message: err.to_string(),
+                }),
+
+            None => Ok(None),
+        }
+    }
+
+    pub fn insert<N, K, V>(&mut self, namespace: N, key: K, value: V) -> Option<Value>
+    where
+        N: ToString,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.namespaces
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(key.to_string(), value.into())
+    }
+
+    pub fn insert_as<N, K, V>(&mut self, namespace: N, key: K, value: &V) -> codec::Result<()>
+    where
+        N: ToString,
+        K: ToString,
+        V: Serialize,
+    {
+        let namespace = namespace.to_string();
+        let key = key.to_string();
+
+        let value =
+            serde_json::to_value(value).map_err(|err| codec::Error::ExtensionConvertFailed {
+                namespace: namespace.clone(),
+                key: key.clone(),
+
+                message: err.to_string(),
+            })?;
+
+        self.insert(namespace, key, value);
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, namespace: &str, key: &str) -> Option<Value> {
+        let entries = self.namespaces.get_mut(namespace)?;
+
+        let value = entries.remove(key);
+
+        if entries.is_empty() {
+            self.namespaces.remove(namespace);
+        }
+
+        value
+    }
+
+    pub fn remove_namespace(&mut self, namespace: &str) -> Option<Map<String, Value>> {
+        self.namespaces.remove(namespace)
+    }
+}
+
+impl Codec for Extensions {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(Value::Object(
+            self.namespaces
+                .iter()
+                .map(|(namespace, entries)| (namespace.clone(), Value::Object(entries.clone())))
+                .collect(),
+        ));
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        let namespaces = reader
+            .value()
+            .as_object()
+            .ok_or(codec::Error::MismatchType {
+                expected: "an object".to_string(),
+                found: reader.value().to_string(),
+            })?
+            .iter()
+            .filter_map(|(namespace, entries)| match entries.as_object() {
+                Some(entries) if entries.is_empty() => None,
+
+                Some(entries) => Some(Ok((namespace.clone(), entries.clone()))),
+
+                None => Some(Err(codec::Error::MismatchType {
+                    expected: "an object".to_string(),
+                    found: entries.to_string(),
+                })),
+            })
+            .collect::<codec::Result<_>>()?;
+
+        Ok(Extensions { namespaces })
+    }
+}
+
+impl Schema for Extensions {
+    fn schema(_: u8) -> codec::Result<Value> {
+        Ok(json!({
+            "type": "object",
+            "additionalProperties": {
+                "type": "object",
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Codec;
+    use crate::Extensions;
+    use crate::codec;
+    use crate::codec::Reader;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use crate::source::EmptySource;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn new() {
+        let extensions = Extensions::new();
+
+        assert!(extensions.is_empty());
+
+        assert!(extensions.get("com.example.ocr", "confidence").is_none());
+    }
+
+    #[test]
+    fn insert() {
+        let mut extensions = Extensions::new();
+
+        extensions.insert("com.example.ocr", "confidence", 0.75);
+        extensions.insert("com.example.qa", "confidence", "high");
+
+        assert_eq!(
+            extensions.get("com.example.ocr", "confidence"),
+            Some(&json!(0.75))
+        );
+
+        assert_eq!(
+            extensions.get("com.example.qa", "confidence"),
+            Some(&json!("high"))
+        );
+    }
+
+    #[test]
+    fn get_as() {
+        let mut extensions = Extensions::new();
+
+        assert!(
+            extensions
+                .insert_as("com.example.bubble", "ids", &vec![1, 2, 3])
+                .is_ok()
+        );
+
+        match extensions.get_as::<Vec<u32>>("com.example.bubble", "ids") {
+            Ok(Some(ids)) => assert_eq!(ids, vec![1, 2, 3]),
+
+            Ok(None) => panic!("Expected ids, found None"),
+            Err(err) => panic!("Failed to read ids: {:?}", err),
+        }
+
+        match extensions.get_as::<String>("com.example.bubble", "ids") {
+            Err(codec::Error::ExtensionConvertFailed {
+                namespace,
+                key,
+                message,
+            }) => {
+                assert_eq!(namespace, "com.example.bubble");
+                assert_eq!(key, "ids");
+                assert!(message.starts_with("invalid type"));
+            }
+
+            result => panic!("Expected a conversion failure, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let manifest = Manifest::default();
+
+        let value = json!({ "com.example.ocr": {}, "com.example.qa": { "flagged": true } });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Extensions::decode(&reader) {
+            Ok(extensions) => {
+                assert_eq!(
+                    extensions.namespaces().collect::<Vec<_>>(),
+                    vec!["com.example.qa"]
+                );
+            }
+
+            Err(err) => panic!("Failed to decode extensions: {:?}", err),
+        }
+
+        let value = json!({ "com.example.ocr": {} });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Extensions::decode(&reader) {
+            Ok(extensions) => assert!(extensions.is_empty()),
+            Err(err) => panic!("Failed to decode extensions: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let mut extensions = Extensions::new();
+
+        extensions.insert("com.example.ocr", "confidence", 0.75);
+
+        assert_eq!(
+            extensions.remove("com.example.ocr", "confidence"),
+            Some(json!(0.75))
+        );
+
+        assert!(extensions.is_empty());
+    }
+
+    #[test]
+    fn encode() {
+        let mut extensions = Extensions::new();
+
+        extensions.insert("com.example.ocr", "confidence", 0.75);
+        extensions.insert("com.example.qa", "flagged", true);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&extensions, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "com.example.ocr": {
+                    "confidence": 0.75
+                },
+                "com.example.qa": {
+                    "flagged": true
+                }
+            })
+        );
+    }
+}
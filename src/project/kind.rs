@@ -0,0 +1,95 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Dialogue,
+    Sfx,
+    Narration,
+    Sign,
+    Aside,
+    Custom(String),
+}
+
+impl Kind {
+    pub const BUILTIN: [Kind; 5] = [
+        Kind::Dialogue,
+        Kind::Sfx,
+        Kind::Narration,
+        Kind::Sign,
+        Kind::Aside,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Kind::Dialogue => "dialogue",
+            Kind::Sfx => "sfx",
+            Kind::Narration => "narration",
+            Kind::Sign => "sign",
+            Kind::Aside => "aside",
+            Kind::Custom(name) => name,
+        }
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        !matches!(self, Kind::Custom(_))
+    }
+}
+
+impl From<&str> for Kind {
+    fn from(name: &str) -> Self {
+        Kind::BUILTIN
+            .into_iter()
+            .find(|kind| kind.as_str() == name)
+            .unwrap_or_else(|| Kind::Custom(name.to_string()))
+    }
+}
+
+impl From<String> for Kind {
+    fn from(name: String) -> Self {
+        Kind::from(name.as_str())
+    }
+}
+
+impl Codec for Kind {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        String::decode(reader).map(Kind::from)
+    }
+}
+
+impl Schema for Kind {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => String::schema(version),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Kind;
+
+    #[test]
+    fn from() {
+        assert_eq!(Kind::from("sfx"), Kind::Sfx);
+        assert_eq!(
+            Kind::from("flashback"),
+            Kind::Custom("flashback".to_string())
+        );
+
+        assert_eq!(Kind::from("flashback").as_str(), "flashback");
+        assert!(!Kind::from("flashback").is_builtin());
+    }
+}
@@ -1,15 +1,38 @@
 use crate::Codec;
 use crate::codec;
 use crate::codec::Reader;
+use crate::codec::Schema;
 use crate::codec::Writer;
+use crate::project::Extensions;
+use crate::project::Kind;
+use crate::project::Session;
+use crate::project::Shape;
+use crate::project::Style;
 use crate::project::Text;
+use crate::project::Thread;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default)]
 pub struct Note {
     x: f32,
     y: f32,
 
+    shape: Option<Shape>,
+
+    kind: Option<Kind>,
+
+    style: Option<String>,
+    overrides: Style,
+
     texts: Vec<Text>,
+
+    threads: Vec<Thread>,
+
+    extensions: Extensions,
+
+    session: Option<Arc<Session>>,
 }
 
 impl Note {
@@ -57,19 +80,171 @@ impl Note {
         self
     }
 
+    pub fn shape(&self) -> Option<&Shape> {
+        self.shape.as_ref()
+    }
+
+    pub fn set_shape(&mut self, shape: Option<Shape>) {
+        self.shape = shape;
+    }
+
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+
+        self
+    }
+
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.shape
+            .as_ref()
+            .is_some_and(|shape| shape.contains(x, y))
+    }
+
+    pub fn kind(&self) -> Option<&Kind> {
+        self.kind.as_ref()
+    }
+
+    pub fn set_kind(&mut self, kind: Option<Kind>) {
+        self.kind = kind;
+    }
+
+    pub fn with_kind<K>(mut self, kind: K) -> Self
+    where
+        K: Into<Kind>,
+    {
+        self.kind = Some(kind.into());
+
+        self
+    }
+
+    pub fn style(&self) -> Option<&str> {
+        self.style.as_deref()
+    }
+
+    pub fn set_style(&mut self, style: Option<String>) {
+        self.style = style;
+    }
+
+    pub fn with_style<S>(mut self, style: S) -> Self
+    where
+        S: ToString,
+    {
+        self.style = Some(style.to_string());
+
+        self
+    }
+
+    pub fn overrides(&self) -> &Style {
+        &self.overrides
+    }
+
+    pub fn overrides_mut(&mut self) -> &mut Style {
+        &mut self.overrides
+    }
+
+    pub fn with_overrides(mut self, overrides: Style) -> Self {
+        self.overrides = overrides;
+
+        self
+    }
+
+    pub fn source(&self) -> Option<&Text> {
+        self.texts.iter().find(|text| text.is_source())
+    }
+
+    pub fn translation(&self, language: &str) -> Option<&Text> {
+        self.translations()
+            .find(|text| text.language().eq_ignore_ascii_case(language))
+    }
+
+    pub fn translations(&self) -> impl Iterator<Item = &Text> {
+        self.texts.iter().filter(|text| !text.is_source())
+    }
+
     pub fn texts(&self) -> &Vec<Text> {
         &self.texts
     }
 
     pub fn texts_mut(&mut self) -> &mut Vec<Text> {
+        if let Some(session) = &self.session {
+            for text in &mut self.texts {
+                text.attach(session);
+            }
+        }
+
         &mut self.texts
     }
 
-    pub fn with_text(mut self, text: Text) -> Self {
+    pub fn with_text(mut self, mut text: Text) -> Self {
+        if let Some(session) = &self.session {
+            text.attach(session);
+        }
+
         self.texts.push(text);
 
         self
     }
+
+    pub fn threads(&self) -> &Vec<Thread> {
+        &self.threads
+    }
+
+    pub fn threads_mut(&mut self) -> &mut Vec<Thread> {
+        &mut self.threads
+    }
+
+    pub fn with_thread(mut self, thread: Thread) -> Self {
+        self.threads.push(thread);
+
+        self
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    pub fn with_extension<N, K, V>(mut self, namespace: N, key: K, value: V) -> Self
+    where
+        N: ToString,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.extensions.insert(namespace, key, value);
+
+        self
+    }
+
+    pub(crate) fn attach(&mut self, session: &Arc<Session>) {
+        self.session = Some(Arc::clone(session));
+    }
+}
+
+impl Clone for Note {
+    fn clone(&self) -> Self {
+        Note {
+            x: self.x,
+            y: self.y,
+
+            shape: self.shape.clone(),
+
+            kind: self.kind.clone(),
+
+            style: self.style.clone(),
+            overrides: self.overrides.clone(),
+
+            texts: self.texts.clone(),
+
+            threads: self.threads.clone(),
+
+            extensions: self.extensions.clone(),
+
+            session: None,
+        }
+    }
 }
 
 impl Codec for Note {
@@ -79,8 +254,32 @@ impl Codec for Note {
                 writer.field("x", &self.x)?;
                 writer.field("y", &self.y)?;
 
+                if self.shape.is_some() {
+                    writer.field("shape", &self.shape)?;
+                }
+
+                if self.kind.is_some() {
+                    writer.field("kind", &self.kind)?;
+                }
+
+                if self.style.is_some() {
+                    writer.field("style", &self.style)?;
+                }
+
+                if !self.overrides.is_empty() {
+                    writer.field("overrides", &self.overrides)?;
+                }
+
                 writer.field("texts", &self.texts)?;
 
+                if !self.threads.is_empty() {
+                    writer.field("threads", &self.threads)?;
+                }
+
+                if !self.extensions.is_empty() {
+                    writer.field("extensions", &self.extensions)?;
+                }
+
                 Ok(())
             }
 
@@ -94,7 +293,20 @@ impl Codec for Note {
                 x: reader.field("x")?,
                 y: reader.field("y")?,
 
+                shape: reader.field_or_default("shape")?,
+
+                kind: reader.field_or_default("kind")?,
+
+                style: reader.field_or_default("style")?,
+                overrides: reader.field_or_default("overrides")?,
+
                 texts: reader.field("texts")?,
+
+                threads: reader.field_or_default("threads")?,
+
+                extensions: reader.field_or_default("extensions")?,
+
+                session: Some(reader.session()),
             }),
 
             version => Err(codec::Error::UnsupportedVersion { version }),
@@ -102,10 +314,38 @@ impl Codec for Note {
     }
 }
 
+impl Schema for Note {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "x": f32::schema(version)?,
+                    "y": f32::schema(version)?,
+                    "shape": Option::<Shape>::schema(version)?,
+                    "kind": Option::<Kind>::schema(version)?,
+                    "style": Option::<String>::schema(version)?,
+                    "overrides": Style::schema(version)?,
+                    "texts": Vec::<Text>::schema(version)?,
+                    "threads": Vec::<Thread>::schema(version)?,
+
+                    "extensions": Extensions::schema(version)?,
+                },
+                "required": ["x", "y", "texts"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Codec;
+    use crate::Kind;
     use crate::Note;
+    use crate::Role;
+    use crate::Shape;
     use crate::Text;
     use crate::codec::Writer;
     use crate::file::Manifest;
@@ -247,4 +487,94 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn with_shape() {
+        let note = Note::new()
+            .with_position(0.5, 0.5)
+            .with_shape(Shape::rectangle(0.25, 0.25, 0.5, 0.5));
+
+        assert!(note.contains(0.3, 0.7));
+        assert!(!note.contains(0.8, 0.8));
+
+        assert!(!Note::new().contains(0.0, 0.0));
+    }
+
+    #[test]
+    fn encode_shape() {
+        let note = Note::new().with_shape(Shape::rectangle(0.25, 0.25, 0.5, 0.5));
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&note, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "x": 0.0,
+                "y": 0.0,
+                "shape": {
+                    "type": "rectangle",
+                    "x": 0.25,
+                    "y": 0.25,
+                    "width": 0.5,
+                    "height": 0.5,
+                },
+                "texts": [],
+            })
+        );
+    }
+
+    #[test]
+    fn encode_kind() {
+        let note = Note::new().with_kind(Kind::Narration);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&note, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "x": 0.0,
+                "y": 0.0,
+                "kind": "narration",
+                "texts": [],
+            })
+        );
+    }
+
+    #[test]
+    fn translation() {
+        let note = Note::new()
+            .with_text(
+                Text::new()
+                    .with_content("ドカーン")
+                    .with_language("ja")
+                    .with_role(Role::Source),
+            )
+            .with_text(Text::new().with_content("轰").with_language("zh-Hans"))
+            .with_text(Text::new().with_content("BOOM").with_language("en"));
+
+        match note.source() {
+            Some(text) => assert_eq!(text.content(), "ドカーン"),
+            None => panic!("Expected source, found None"),
+        }
+
+        match note.translation("zh-hans") {
+            Some(text) => assert_eq!(text.content(), "轰"),
+            None => panic!("Expected translation, found None"),
+        }
+
+        assert!(note.translation("ja").is_none());
+        assert_eq!(note.translations().count(), 2);
+    }
 }
@@ -0,0 +1,273 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use crate::project::Note;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    #[default]
+    RightToLeft,
+    LeftToRight,
+    Vertical,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 3] = [
+        Direction::RightToLeft,
+        Direction::LeftToRight,
+        Direction::Vertical,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::RightToLeft => "rtl",
+            Direction::LeftToRight => "ltr",
+            Direction::Vertical => "vertical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingOrder {
+    direction: Direction,
+
+    tolerance: f32,
+}
+
+impl ReadingOrder {
+    pub const DEFAULT_TOLERANCE: f32 = 0.05;
+
+    pub fn new(direction: Direction) -> Self {
+        ReadingOrder {
+            direction,
+
+            tolerance: ReadingOrder::DEFAULT_TOLERANCE,
+        }
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+
+        self
+    }
+
+    pub fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    pub fn set_tolerance(&mut self, tolerance: f32) {
+        self.tolerance = tolerance;
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    pub fn indices(&self, notes: &[Note]) -> Vec<usize> {
+        let key = |note: &Note| match self.direction {
+            Direction::RightToLeft => (note.y(), -note.x()),
+            Direction::LeftToRight => (note.y(), note.x()),
+            Direction::Vertical => (-note.x(), note.y()),
+        };
+
+        let mut indices = (0..notes.len()).collect::<Vec<_>>();
+
+        indices.sort_by(|a, b| key(&notes[*a]).0.total_cmp(&key(&notes[*b]).0));
+
+        let mut bands: Vec<Vec<usize>> = Vec::new();
+
+        let mut start = f32::NEG_INFINITY;
+
+        for index in indices {
+            let (primary, _) = key(&notes[index]);
+
+            match bands.last_mut() {
+                Some(band) if primary - start <= self.tolerance => band.push(index),
+
+                _ => {
+                    start = primary;
+
+                    bands.push(vec![index]);
+                }
+            }
+        }
+
+        bands
+            .into_iter()
+            .flat_map(|mut band| {
+                band.sort_by(|a, b| key(&notes[*a]).1.total_cmp(&key(&notes[*b]).1));
+
+                band
+            })
+            .collect()
+    }
+
+    pub fn sort(&self, notes: &mut Vec<Note>) {
+        let indices = self.indices(notes);
+
+        let mut slots = notes.drain(..).map(Some).collect::<Vec<_>>();
+
+        notes.extend(indices.into_iter().filter_map(|index| slots[index].take()));
+    }
+}
+
+impl Default for ReadingOrder {
+    fn default() -> Self {
+        ReadingOrder::new(Direction::default())
+    }
+}
+
+impl Codec for Direction {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| reader.value().as_str() == Some(direction.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "a direction".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Direction {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Direction::ALL.map(|direction| direction.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Codec for ReadingOrder {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.field("direction", &self.direction)?;
+
+                writer.field("tolerance", &self.tolerance)?;
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => Ok(ReadingOrder {
+                direction: reader.field("direction")?,
+
+                tolerance: reader.field("tolerance")?,
+            }),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for ReadingOrder {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "direction": Direction::schema(version)?,
+                    "tolerance": f32::schema(version)?,
+                },
+                "required": ["direction", "tolerance"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Direction;
+    use crate::Note;
+    use crate::ReadingOrder;
+
+    fn notes() -> Vec<Note> {
+        vec![
+            Note::new().with_position(0.2, 0.52),
+            Note::new().with_position(0.8, 0.1),
+            Note::new().with_position(0.3, 0.12),
+            Note::new().with_position(0.7, 0.5),
+        ]
+    }
+
+    #[test]
+    fn right_to_left() {
+        let order = ReadingOrder::new(Direction::RightToLeft);
+
+        assert_eq!(order.indices(&notes()), vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn left_to_right() {
+        let order = ReadingOrder::new(Direction::LeftToRight);
+
+        assert_eq!(order.indices(&notes()), vec![2, 1, 0, 3]);
+    }
+
+    #[test]
+    fn vertical() {
+        let order = ReadingOrder::new(Direction::Vertical).with_tolerance(0.15);
+
+        assert_eq!(order.indices(&notes()), vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn tolerance() {
+        let order = ReadingOrder::new(Direction::RightToLeft).with_tolerance(0.0);
+
+        assert_eq!(order.indices(&notes()), vec![1, 2, 3, 0]);
+
+        let order = order.with_tolerance(1.0);
+
+        assert_eq!(order.indices(&notes()), vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn sort() {
+        let mut notes = notes();
+
+        ReadingOrder::new(Direction::RightToLeft).sort(&mut notes);
+
+        let positions = notes
+            .iter()
+            .map(|note| (note.x(), note.y()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            positions,
+            vec![(0.8, 0.1), (0.3, 0.12), (0.7, 0.5), (0.2, 0.52)]
+        );
+    }
+}
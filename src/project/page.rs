@@ -1,15 +1,42 @@
 use crate::Codec;
 use crate::codec;
 use crate::codec::Reader;
+use crate::codec::Schema;
 use crate::codec::Writer;
 use crate::project::Asset;
+use crate::project::AssetCache;
+use crate::project::Extensions;
+use crate::project::Kind;
 use crate::project::Note;
+use crate::project::ReadingOrder;
+use crate::project::Session;
+use crate::project::Status;
+use crate::project::Thread;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
 
 #[derive(Debug, Default)]
 pub struct Page {
     image: Option<Asset>,
 
+    label: String,
+
+    status: Status,
+
+    dimensions: Option<(u32, u32)>,
+
+    spread: Option<u32>,
+
     notes: Vec<Note>,
+
+    threads: Vec<Thread>,
+
+    extensions: Extensions,
+
+    session: Option<Arc<Session>>,
+
+    cache: Option<Arc<AssetCache>>,
 }
 
 impl Page {
@@ -21,29 +48,162 @@ impl Page {
         self.image.as_ref()
     }
 
-    pub fn set_image(&mut self, image: Option<Asset>) {
+    pub fn set_image(&mut self, mut image: Option<Asset>) {
+        if let (Some(image), Some(cache)) = (&mut image, &self.cache) {
+            image.attach(cache);
+        }
+
         self.image = image;
     }
 
     pub fn with_image(mut self, image: Asset) -> Self {
-        self.image = Some(image);
+        self.set_image(Some(image));
+
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn set_label<L>(&mut self, label: L)
+    where
+        L: ToString,
+    {
+        self.label = label.to_string();
+    }
+
+    pub fn with_label<L>(mut self, label: L) -> Self
+    where
+        L: ToString,
+    {
+        self.label = label.to_string();
 
         self
     }
 
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: Status) {
+        self.status = status;
+    }
+
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+
+        self
+    }
+
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        self.dimensions
+    }
+
+    pub fn set_dimensions(&mut self, dimensions: Option<(u32, u32)>) {
+        self.dimensions = dimensions;
+    }
+
+    pub fn with_dimensions(mut self, width: u32, height: u32) -> Self {
+        self.dimensions = Some((width, height));
+
+        self
+    }
+
+    pub fn spread(&self) -> Option<u32> {
+        self.spread
+    }
+
+    pub fn set_spread(&mut self, spread: Option<u32>) {
+        self.spread = spread;
+    }
+
+    pub fn with_spread(mut self, spread: u32) -> Self {
+        self.spread = Some(spread);
+
+        self
+    }
+
+    pub fn thumbnail(&self) -> Option<Asset> {
+        self.image.as_ref()?.rendition(Asset::THUMBNAIL)
+    }
+
     pub fn notes(&self) -> &Vec<Note> {
         &self.notes
     }
 
     pub fn notes_mut(&mut self) -> &mut Vec<Note> {
+        if let Some(session) = &self.session {
+            for note in &mut self.notes {
+                note.attach(session);
+            }
+        }
+
         &mut self.notes
     }
 
-    pub fn with_note(mut self, note: Note) -> Self {
+    pub fn with_note(mut self, mut note: Note) -> Self {
+        if let Some(session) = &self.session {
+            note.attach(session);
+        }
+
         self.notes.push(note);
 
         self
     }
+
+    pub fn sort_notes(&mut self, order: &ReadingOrder) {
+        order.sort(&mut self.notes);
+    }
+
+    pub fn notes_of_kind<'a>(&'a self, kind: &'a Kind) -> impl Iterator<Item = &'a Note> {
+        self.notes
+            .iter()
+            .filter(move |note| note.kind() == Some(kind))
+    }
+
+    pub fn threads(&self) -> &Vec<Thread> {
+        &self.threads
+    }
+
+    pub fn threads_mut(&mut self) -> &mut Vec<Thread> {
+        &mut self.threads
+    }
+
+    pub fn with_thread(mut self, thread: Thread) -> Self {
+        self.threads.push(thread);
+
+        self
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    pub fn with_extension<N, K, V>(mut self, namespace: N, key: K, value: V) -> Self
+    where
+        N: ToString,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.extensions.insert(namespace, key, value);
+
+        self
+    }
+
+    pub(crate) fn attach(&mut self, session: &Arc<Session>, cache: &Arc<AssetCache>) {
+        if let Some(image) = &mut self.image {
+            image.attach(cache);
+        }
+
+        self.session = Some(Arc::clone(session));
+
+        self.cache = Some(Arc::clone(cache));
+    }
 }
 
 impl Codec for Page {
@@ -52,8 +212,37 @@ impl Codec for Page {
             0 => {
                 writer.field("image", &self.image)?;
 
+                if let Some(image) = &self.image {
+                    image.encode_renditions(writer)?;
+                }
+
+                if !self.label.is_empty() {
+                    writer.field("label", &self.label)?;
+                }
+
+                if self.status != Status::default() {
+                    writer.field("status", &self.status)?;
+                }
+
+                if let Some((width, height)) = self.dimensions {
+                    writer.field("width", &width)?;
+                    writer.field("height", &height)?;
+                }
+
+                if self.spread.is_some() {
+                    writer.field("spread", &self.spread)?;
+                }
+
                 writer.field("notes", &self.notes)?;
 
+                if !self.threads.is_empty() {
+                    writer.field("threads", &self.threads)?;
+                }
+
+                if !self.extensions.is_empty() {
+                    writer.field("extensions", &self.extensions)?;
+                }
+
                 Ok(())
             }
 
@@ -66,7 +255,42 @@ impl Codec for Page {
             0 => Ok(Page {
                 image: reader.field("image")?,
 
+                label: reader.field_or_default("label")?,
+
+                status: reader.field_or_default("status")?,
+
+                dimensions: match (
+                    reader.field_or_default("width")?,
+                    reader.field_or_default("height")?,
+                ) {
+                    (Some(width), Some(height)) => Some((width, height)),
+
+                    (Some(_), None) => {
+                        return Err(codec::Error::MissingField {
+                            field: "height".to_string(),
+                        });
+                    }
+
+                    (None, Some(_)) => {
+                        return Err(codec::Error::MissingField {
+                            field: "width".to_string(),
+                        });
+                    }
+
+                    (None, None) => None,
+                },
+
+                spread: reader.field_or_default("spread")?,
+
                 notes: reader.field("notes")?,
+
+                threads: reader.field_or_default("threads")?,
+
+                extensions: reader.field_or_default("extensions")?,
+
+                session: Some(reader.session()),
+
+                cache: Some(reader.cache()),
             }),
 
             version => Err(codec::Error::UnsupportedVersion { version }),
@@ -74,16 +298,46 @@ impl Codec for Page {
     }
 }
 
+impl Schema for Page {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "image": Option::<Asset>::schema(version)?,
+                    "label": String::schema(version)?,
+                    "status": Status::schema(version)?,
+                    "width": u32::schema(version)?,
+                    "height": u32::schema(version)?,
+                    "spread": Option::<u32>::schema(version)?,
+                    "notes": Vec::<Note>::schema(version)?,
+                    "threads": Vec::<Thread>::schema(version)?,
+
+                    "extensions": Extensions::schema(version)?,
+                },
+                "required": ["image", "notes"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Asset;
     use crate::Codec;
     use crate::Note;
     use crate::Page;
+    use crate::Status;
     use crate::Text;
+    use crate::codec;
+    use crate::codec::Reader;
     use crate::codec::Writer;
     use crate::file::Manifest;
+    use crate::source::EmptySource;
     use serde_json::json;
+    use std::sync::Arc;
 
     #[test]
     fn new() {
@@ -140,6 +394,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn with_metadata() {
+        let page = Page::new()
+            .with_label("p. 12")
+            .with_status(Status::Proofread)
+            .with_dimensions(1200, 1800);
+
+        assert_eq!(page.label(), "p. 12");
+        assert_eq!(page.status(), Status::Proofread);
+        assert_eq!(page.dimensions(), Some((1200, 1800)));
+    }
+
+    #[test]
+    fn encode_metadata() {
+        let page = Page::new()
+            .with_label("Cover")
+            .with_status(Status::Done)
+            .with_dimensions(1200, 1800);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&page, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "image": null,
+                "label": "Cover",
+                "status": "done",
+                "width": 1200,
+                "height": 1800,
+                "notes": [],
+            }),
+        );
+    }
+
+    #[test]
+    fn decode_dimensions() {
+        let manifest = Manifest::default();
+
+        let value = json!({ "image": null, "width": 1200, "height": 1800, "notes": [] });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Page::decode(&reader) {
+            Ok(page) => assert_eq!(page.dimensions(), Some((1200, 1800))),
+            Err(err) => panic!("Failed to decode page: {:?}", err),
+        }
+
+        let value = json!({ "image": null, "width": 1200, "notes": [] });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Page::decode(&reader) {
+            Err(codec::Error::MissingField { field }) => assert_eq!(field, "height"),
+            result => panic!("Expected missing height, found {:?}", result),
+        }
+
+        let value = json!({ "image": null, "height": 1800, "notes": [] });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Page::decode(&reader) {
+            Err(codec::Error::MissingField { field }) => assert_eq!(field, "width"),
+            result => panic!("Expected missing width, found {:?}", result),
+        }
+    }
+
     #[test]
     fn encode() {
         let asset = Asset::new("path/to/image.png", Vec::new());
@@ -0,0 +1,198 @@
+use std::io;
+use std::io::Read;
+use std::ops::Range;
+
+const HEADER: u64 = 32;
+
+pub(crate) fn mime(header: &[u8]) -> &'static str {
+    let at = |range: Range<usize>| header.get(range).unwrap_or_default();
+
+    match header {
+        [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, ..] => "image/png",
+        [0xff, 0xd8, 0xff, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', ..] if at(8..12) == b"WEBP" => "image/webp",
+        _ if at(4..12) == b"ftypavif" => "image/avif",
+        [b'B', b'M', ..] => "image/bmp",
+        [b'I', b'I', 0x2a, 0x00, ..] | [b'M', b'M', 0x00, 0x2a, ..] => "image/tiff",
+        [b'8', b'B', b'P', b'S', ..] => "image/vnd.adobe.photoshop",
+
+        _ => "application/octet-stream",
+    }
+}
+
+pub(crate) fn header(stream: &mut dyn Read) -> io::Result<Vec<u8>> {
+    let mut header = Vec::new();
+
+    stream.take(HEADER).read_to_end(&mut header)?;
+
+    Ok(header)
+}
+
+pub(crate) fn dimensions(stream: &mut dyn Read) -> io::Result<Option<(u32, u32)>> {
+    let header = header(stream)?;
+
+    let at = |offset: usize, len: usize| -> Option<[u8; 4]> {
+        let bytes = header.get(offset..offset + len)?;
+
+        let mut value = [0; 4];
+
+        value[..len].copy_from_slice(bytes);
+
+        Some(value)
+    };
+
+    let be32 = |offset: usize| at(offset, 4).map(u32::from_be_bytes);
+    let le32 = |offset: usize| at(offset, 4).map(u32::from_le_bytes);
+    let le24 = |offset: usize| at(offset, 3).map(u32::from_le_bytes);
+    let le16 = |offset: usize| at(offset, 2).map(u32::from_le_bytes);
+
+    let size = match mime(&header) {
+        "image/png" => be32(16).zip(be32(20)),
+
+        "image/gif" => le16(6).zip(le16(8)),
+
+        "image/bmp" => le32(18)
+            .zip(le32(22))
+            .map(|(w, h)| (w, (h as i32).unsigned_abs())),
+
+        "image/vnd.adobe.photoshop" => be32(18).zip(be32(14)),
+
+        "image/webp" => match header.get(12..16) {
+            Some(b"VP8 ") => le16(26)
+                .zip(le16(28))
+                .map(|(w, h)| (w & 0x3fff, h & 0x3fff)),
+
+            Some(b"VP8L") => {
+                le32(21).map(|bits| ((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1))
+            }
+
+            Some(b"VP8X") => le24(24).zip(le24(27)).map(|(w, h)| (w + 1, h + 1)),
+
+            _ => None,
+        },
+
+        "image/jpeg" => return jpeg(&header[2..], stream),
+
+        _ => None,
+    };
+
+    Ok(size)
+}
+
+fn jpeg(header: &[u8], stream: &mut dyn Read) -> io::Result<Option<(u32, u32)>> {
+    let mut stream = header.chain(stream);
+
+    let mut byte = [0; 1];
+
+    loop {
+        stream.read_exact(&mut byte)?;
+
+        if byte[0] != 0xff {
+            return Ok(None);
+        }
+
+        let marker = loop {
+            stream.read_exact(&mut byte)?;
+
+            if byte[0] != 0xff {
+                break byte[0];
+            }
+        };
+
+        if matches!(marker, 0x01 | 0xd0..=0xd9) {
+            continue;
+        }
+
+        let mut length = [0; 2];
+
+        stream.read_exact(&mut length)?;
+
+        let length = u16::from_be_bytes(length) as u64;
+
+        if length < 2 {
+            return Ok(None);
+        }
+
+        if matches!(marker, 0xc0..=0xcf) && !matches!(marker, 0xc4 | 0xc8 | 0xcc) {
+            let mut frame = [0; 5];
+
+            stream.read_exact(&mut frame)?;
+
+            let height = u16::from_be_bytes([frame[1], frame[2]]) as u32;
+            let width = u16::from_be_bytes([frame[3], frame[4]]) as u32;
+
+            return Ok(Some((width, height)));
+        }
+
+        io::copy(&mut (&mut stream).take(length - 2), &mut io::sink())?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::probe;
+
+    #[test]
+    fn png() {
+        let mut data = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+        data.extend([0, 0, 0, 13]);
+        data.extend(b"IHDR");
+        data.extend(640u32.to_be_bytes());
+        data.extend(480u32.to_be_bytes());
+
+        assert_eq!(probe::mime(&data), "image/png");
+
+        match probe::dimensions(&mut data.as_slice()) {
+            Ok(size) => assert_eq!(size, Some((640, 480))),
+            Err(err) => panic!("Failed to probe dimensions: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn jpeg() {
+        let mut data = vec![0xff, 0xd8];
+
+        data.extend([0xff, 0xe0, 0x00, 0x04, 0x00, 0x00]);
+        data.extend([0xff, 0xc0, 0x00, 0x0b, 0x08]);
+        data.extend(480u16.to_be_bytes());
+        data.extend(640u16.to_be_bytes());
+        data.extend([0x01, 0x01, 0x11, 0x00]);
+
+        assert_eq!(probe::mime(&data), "image/jpeg");
+
+        match probe::dimensions(&mut data.as_slice()) {
+            Ok(size) => assert_eq!(size, Some((640, 480))),
+            Err(err) => panic!("Failed to probe dimensions: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn webp() {
+        let mut data = b"RIFF\0\0\0\0WEBPVP8X".to_vec();
+
+        data.extend([0; 8]);
+        data.extend([0x7f, 0x02, 0x00]);
+        data.extend([0xdf, 0x01, 0x00]);
+
+        assert_eq!(probe::mime(&data), "image/webp");
+
+        match probe::dimensions(&mut data.as_slice()) {
+            Ok(size) => assert_eq!(size, Some((640, 480))),
+            Err(err) => panic!("Failed to probe dimensions: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let data = vec![0, 1, 2, 3];
+
+        assert_eq!(probe::mime(&data), "application/octet-stream");
+
+        match probe::dimensions(&mut data.as_slice()) {
+            Ok(size) => assert_eq!(size, None),
+            Err(err) => panic!("Failed to probe dimensions: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+pub(crate) const FOLDER: &str = "renditions";
+
+pub(crate) fn path(path: &str, size: u32) -> String {
+    format!("{}/{}/{}.png", FOLDER, size, path)
+}
+
+#[cfg(feature = "image")]
+pub(crate) fn render(data: &[u8], size: u32) -> Option<Vec<u8>> {
+    use image::ImageFormat;
+    use std::io::Cursor;
+
+    let image = image::load_from_memory(data).ok()?;
+
+    let image = if image.width() > size || image.height() > size {
+        image.thumbnail(size, size)
+    } else {
+        image
+    };
+
+    let mut stream = Cursor::new(Vec::new());
+
+    image.write_to(&mut stream, ImageFormat::Png).ok()?;
+
+    Some(stream.into_inner())
+}
+
+#[cfg(not(feature = "image"))]
+pub(crate) fn render(_: &[u8], _: u32) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::rendition;
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            rendition::path("pages/page_1.webp", 256),
+            "renditions/256/pages/page_1.webp.png"
+        );
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn render() {
+        use image::ImageFormat;
+        use image::RgbImage;
+        use std::io::Cursor;
+
+        let mut stream = Cursor::new(Vec::new());
+
+        if let Err(err) = RgbImage::new(600, 300).write_to(&mut stream, ImageFormat::Png) {
+            panic!("Failed to encode image: {:?}", err);
+        }
+
+        let data = match rendition::render(stream.get_ref(), 256) {
+            Some(val) => val,
+            None => panic!("Failed to render image"),
+        };
+
+        match image::load_from_memory(&data) {
+            Ok(image) => assert_eq!((image.width(), image.height()), (256, 128)),
+            Err(err) => panic!("Failed to decode image: {:?}", err),
+        }
+
+        assert!(rendition::render(&[0, 1, 2, 3], 256).is_none());
+    }
+}
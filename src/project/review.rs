@@ -0,0 +1,68 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use crate::project::Comment;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Review {
+    #[default]
+    Pending,
+    Approved,
+    NeedsChanges,
+    Rejected,
+}
+
+pub type Remark = Comment;
+
+impl Review {
+    pub const ALL: [Review; 4] = [
+        Review::Pending,
+        Review::Approved,
+        Review::NeedsChanges,
+        Review::Rejected,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Review::Pending => "pending",
+            Review::Approved => "approved",
+            Review::NeedsChanges => "needs_changes",
+            Review::Rejected => "rejected",
+        }
+    }
+}
+
+impl Codec for Review {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Review::ALL
+            .into_iter()
+            .find(|review| reader.value().as_str() == Some(review.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "a review state".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Review {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Review::ALL.map(|review| review.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
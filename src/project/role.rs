@@ -0,0 +1,56 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Source,
+    #[default]
+    Translation,
+}
+
+impl Role {
+    pub const ALL: [Role; 2] = [Role::Source, Role::Translation];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Source => "source",
+            Role::Translation => "translation",
+        }
+    }
+}
+
+impl Codec for Role {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Role::ALL
+            .into_iter()
+            .find(|role| reader.value().as_str() == Some(role.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "a role".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Role {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Role::ALL.map(|role| role.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
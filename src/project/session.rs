@@ -0,0 +1,29 @@
+use std::sync::RwLock;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+#[derive(Debug, Default)]
+pub(crate) struct Session {
+    identity: RwLock<Option<String>>,
+}
+
+impl Session {
+    pub(crate) fn identity(&self) -> Option<String> {
+        self.identity
+            .read()
+            .ok()
+            .and_then(|identity| identity.clone())
+    }
+
+    pub(crate) fn set_identity(&self, identity: Option<String>) {
+        if let Ok(mut current) = self.identity.write() {
+            *current = identity;
+        }
+    }
+}
+
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
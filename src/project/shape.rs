@@ -0,0 +1,372 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Rectangle {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+
+    RotatedRectangle {
+        cx: f32,
+        cy: f32,
+        width: f32,
+        height: f32,
+        rotation: f32,
+    },
+
+    Polygon {
+        points: Vec<(f32, f32)>,
+    },
+}
+
+impl Shape {
+    pub fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Shape::Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn rotated_rectangle(cx: f32, cy: f32, width: f32, height: f32, rotation: f32) -> Self {
+        Shape::RotatedRectangle {
+            cx,
+            cy,
+            width,
+            height,
+            rotation,
+        }
+    }
+
+    pub fn polygon<P>(points: P) -> Self
+    where
+        P: IntoIterator<Item = (f32, f32)>,
+    {
+        Shape::Polygon {
+            points: points.into_iter().collect(),
+        }
+    }
+
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        match self {
+            Shape::Rectangle {
+                x: left,
+                y: top,
+                width,
+                height,
+            } => (*left..=left + width).contains(&x) && (*top..=top + height).contains(&y),
+
+            Shape::RotatedRectangle {
+                cx,
+                cy,
+                width,
+                height,
+                rotation,
+            } => {
+                let (sin, cos) = (-rotation.to_radians()).sin_cos();
+
+                let (dx, dy) = (x - cx, y - cy);
+
+                let u = dx * cos - dy * sin;
+                let v = dx * sin + dy * cos;
+
+                u.abs() <= width / 2.0 && v.abs() <= height / 2.0
+            }
+
+            Shape::Polygon { points } => {
+                let mut inside = false;
+
+                for (index, &(xi, yi)) in points.iter().enumerate() {
+                    let (xj, yj) = points[(index + points.len() - 1) % points.len()];
+
+                    if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                        inside = !inside;
+                    }
+                }
+
+                inside
+            }
+        }
+    }
+
+    pub fn area(&self) -> f32 {
+        match self {
+            Shape::Rectangle { width, height, .. }
+            | Shape::RotatedRectangle { width, height, .. } => (width * height).abs(),
+
+            Shape::Polygon { points } => {
+                let sum = points
+                    .iter()
+                    .zip(points.iter().cycle().skip(1))
+                    .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+                    .sum::<f32>();
+
+                sum.abs() / 2.0
+            }
+        }
+    }
+
+    pub fn bounding_box(&self) -> (f32, f32, f32, f32) {
+        let points = self.points();
+
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in &points {
+            min_x = min_x.min(*x);
+            min_y = min_y.min(*y);
+            max_x = max_x.max(*x);
+            max_y = max_y.max(*y);
+        }
+
+        if points.is_empty() {
+            return (0.0, 0.0, 0.0, 0.0);
+        }
+
+        (min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    pub fn points(&self) -> Vec<(f32, f32)> {
+        match self {
+            Shape::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } => vec![
+                (*x, *y),
+                (x + width, *y),
+                (x + width, y + height),
+                (*x, y + height),
+            ],
+
+            Shape::RotatedRectangle {
+                cx,
+                cy,
+                width,
+                height,
+                rotation,
+            } => {
+                let (sin, cos) = rotation.to_radians().sin_cos();
+
+                [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                    .into_iter()
+                    .map(|(u, v)| {
+                        let (u, v) = (u * width / 2.0, v * height / 2.0);
+
+                        (cx + u * cos - v * sin, cy + u * sin + v * cos)
+                    })
+                    .collect()
+            }
+
+            Shape::Polygon { points } => points.clone(),
+        }
+    }
+}
+
+impl Codec for Shape {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                match self {
+                    Shape::Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                    } => {
+                        writer.field("type", &"rectangle".to_string())?;
+
+                        writer.field("x", x)?;
+                        writer.field("y", y)?;
+                        writer.field("width", width)?;
+                        writer.field("height", height)?;
+                    }
+
+                    Shape::RotatedRectangle {
+                        cx,
+                        cy,
+                        width,
+                        height,
+                        rotation,
+                    } => {
+                        writer.field("type", &"rotated_rectangle".to_string())?;
+
+                        writer.field("cx", cx)?;
+                        writer.field("cy", cy)?;
+                        writer.field("width", width)?;
+                        writer.field("height", height)?;
+                        writer.field("rotation", rotation)?;
+                    }
+
+                    Shape::Polygon { points } => {
+                        writer.field("type", &"polygon".to_string())?;
+
+                        writer.field("points", points)?;
+                    }
+                }
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => match reader.field::<_, String>("type")?.as_str() {
+                "rectangle" => Ok(Shape::Rectangle {
+                    x: reader.field("x")?,
+                    y: reader.field("y")?,
+                    width: reader.field("width")?,
+                    height: reader.field("height")?,
+                }),
+
+                "rotated_rectangle" => Ok(Shape::RotatedRectangle {
+                    cx: reader.field("cx")?,
+                    cy: reader.field("cy")?,
+                    width: reader.field("width")?,
+                    height: reader.field("height")?,
+                    rotation: reader.field("rotation")?,
+                }),
+
+                "polygon" => Ok(Shape::Polygon {
+                    points: reader.field("points")?,
+                }),
+
+                found => Err(codec::Error::MismatchType {
+                    expected: "a shape type".to_string(),
+                    found: found.to_string(),
+                }),
+            },
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Shape {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "anyOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["rectangle"] },
+                            "x": f32::schema(version)?,
+                            "y": f32::schema(version)?,
+                            "width": f32::schema(version)?,
+                            "height": f32::schema(version)?,
+                        },
+                        "required": ["type", "x", "y", "width", "height"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["rotated_rectangle"] },
+                            "cx": f32::schema(version)?,
+                            "cy": f32::schema(version)?,
+                            "width": f32::schema(version)?,
+                            "height": f32::schema(version)?,
+                            "rotation": f32::schema(version)?,
+                        },
+                        "required": ["type", "cx", "cy", "width", "height", "rotation"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["polygon"] },
+                            "points": Vec::<(f32, f32)>::schema(version)?,
+                        },
+                        "required": ["type", "points"],
+                    },
+                ]
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Codec;
+    use crate::Shape;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use serde_json::json;
+
+    #[test]
+    fn rectangle() {
+        let shape = Shape::rectangle(0.25, 0.5, 0.5, 0.25);
+
+        assert!(shape.contains(0.5, 0.6));
+        assert!(!shape.contains(0.1, 0.6));
+
+        assert_eq!(shape.area(), 0.125);
+        assert_eq!(shape.bounding_box(), (0.25, 0.5, 0.5, 0.25));
+    }
+
+    #[test]
+    fn rotated_rectangle() {
+        let shape = Shape::rotated_rectangle(0.5, 0.5, 0.4, 0.2, 90.0);
+
+        assert!(shape.contains(0.5, 0.65));
+        assert!(!shape.contains(0.65, 0.5));
+
+        assert!((shape.area() - 0.08).abs() < 1e-6);
+
+        let (x, y, width, height) = shape.bounding_box();
+
+        assert!((x - 0.4).abs() < 1e-6);
+        assert!((y - 0.3).abs() < 1e-6);
+        assert!((width - 0.2).abs() < 1e-6);
+        assert!((height - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn polygon() {
+        let shape = Shape::polygon([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+
+        assert!(shape.contains(0.25, 0.25));
+        assert!(!shape.contains(0.75, 0.75));
+
+        assert_eq!(shape.area(), 0.5);
+        assert_eq!(shape.bounding_box(), (0.0, 0.0, 1.0, 1.0));
+
+        assert_eq!(Shape::polygon([]).bounding_box(), (0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn encode() {
+        let shape = Shape::polygon([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&shape, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "type": "polygon",
+                "points": [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            })
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Span {
+    Run {
+        content: String,
+
+        bold: bool,
+        italic: bool,
+        emphasis: bool,
+    },
+
+    Ruby {
+        base: String,
+        annotation: String,
+    },
+
+    Break,
+}
+
+impl Span {
+    pub fn run<C>(content: C) -> Self
+    where
+        C: ToString,
+    {
+        Span::Run {
+            content: content.to_string(),
+
+            bold: false,
+            italic: false,
+            emphasis: false,
+        }
+    }
+
+    pub fn ruby<B, A>(base: B, annotation: A) -> Self
+    where
+        B: ToString,
+        A: ToString,
+    {
+        Span::Ruby {
+            base: base.to_string(),
+            annotation: annotation.to_string(),
+        }
+    }
+
+    pub fn line_break() -> Self {
+        Span::Break
+    }
+
+    pub fn with_bold(mut self, value: bool) -> Self {
+        if let Span::Run { bold, .. } = &mut self {
+            *bold = value;
+        }
+
+        self
+    }
+
+    pub fn with_italic(mut self, value: bool) -> Self {
+        if let Span::Run { italic, .. } = &mut self {
+            *italic = value;
+        }
+
+        self
+    }
+
+    pub fn with_emphasis(mut self, value: bool) -> Self {
+        if let Span::Run { emphasis, .. } = &mut self {
+            *emphasis = value;
+        }
+
+        self
+    }
+
+    pub fn is_bold(&self) -> bool {
+        matches!(self, Span::Run { bold: true, .. })
+    }
+
+    pub fn is_italic(&self) -> bool {
+        matches!(self, Span::Run { italic: true, .. })
+    }
+
+    pub fn is_emphasis(&self) -> bool {
+        matches!(self, Span::Run { emphasis: true, .. })
+    }
+
+    pub fn annotation(&self) -> Option<&str> {
+        match self {
+            Span::Ruby { annotation, .. } => Some(annotation),
+
+            _ => None,
+        }
+    }
+
+    pub fn plain(&self) -> &str {
+        match self {
+            Span::Run { content, .. } => content,
+
+            Span::Ruby { base, .. } => base,
+
+            Span::Break => "\n",
+        }
+    }
+}
+
+pub(crate) fn plain(spans: &[Span]) -> String {
+    spans.iter().map(Span::plain).collect()
+}
+
+impl Codec for Span {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                match self {
+                    Span::Run {
+                        content,
+                        bold,
+                        italic,
+                        emphasis,
+                    } => {
+                        writer.field("type", &"run".to_string())?;
+
+                        writer.field("content", content)?;
+
+                        if *bold {
+                            writer.field("bold", bold)?;
+                        }
+
+                        if *italic {
+                            writer.field("italic", italic)?;
+                        }
+
+                        if *emphasis {
+                            writer.field("emphasis", emphasis)?;
+                        }
+                    }
+
+                    Span::Ruby { base, annotation } => {
+                        writer.field("type", &"ruby".to_string())?;
+
+                        writer.field("base", base)?;
+                        writer.field("annotation", annotation)?;
+                    }
+
+                    Span::Break => {
+                        writer.field("type", &"break".to_string())?;
+                    }
+                }
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => match reader.field::<_, String>("type")?.as_str() {
+                "run" => Ok(Span::Run {
+                    content: reader.field("content")?,
+
+                    bold: reader.field_or_default("bold")?,
+                    italic: reader.field_or_default("italic")?,
+                    emphasis: reader.field_or_default("emphasis")?,
+                }),
+
+                "ruby" => Ok(Span::Ruby {
+                    base: reader.field("base")?,
+                    annotation: reader.field("annotation")?,
+                }),
+
+                "break" => Ok(Span::Break),
+
+                found => Err(codec::Error::MismatchType {
+                    expected: "a span type".to_string(),
+                    found: found.to_string(),
+                }),
+            },
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Span {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "anyOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["run"] },
+                            "content": String::schema(version)?,
+                            "bold": bool::schema(version)?,
+                            "italic": bool::schema(version)?,
+                            "emphasis": bool::schema(version)?,
+                        },
+                        "required": ["type", "content"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["ruby"] },
+                            "base": String::schema(version)?,
+                            "annotation": String::schema(version)?,
+                        },
+                        "required": ["type", "base", "annotation"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["break"] },
+                        },
+                        "required": ["type"],
+                    },
+                ]
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Codec;
+    use crate::Span;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use serde_json::json;
+
+    #[test]
+    fn plain() {
+        let spans = [
+            Span::ruby("東京", "とうきょう"),
+            Span::run("へ").with_emphasis(true),
+            Span::line_break(),
+            Span::run("GO!").with_bold(true).with_italic(true),
+        ];
+
+        assert_eq!(super::plain(&spans), "東京へ\nGO!");
+
+        assert!(spans[1].is_emphasis());
+        assert!(spans[3].is_bold());
+        assert!(spans[3].is_italic());
+        assert!(!spans[0].is_bold());
+
+        assert_eq!(spans[0].annotation(), Some("とうきょう"));
+        assert_eq!(spans[1].annotation(), None);
+    }
+
+    #[test]
+    fn encode() {
+        let spans = vec![
+            Span::ruby("東京", "とうきょう"),
+            Span::line_break(),
+            Span::run("GO!").with_bold(true),
+        ];
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&spans, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!([
+                {
+                    "type": "ruby",
+                    "base": "東京",
+                    "annotation": "とうきょう"
+                },
+                {
+                    "type": "break"
+                },
+                {
+                    "type": "run",
+                    "content": "GO!",
+                    "bold": true
+                }
+            ])
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::project::Direction;
+use crate::project::Page;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayUnit<'a> {
+    index: usize,
+
+    pages: &'a [Page],
+
+    direction: Direction,
+}
+
+impl<'a> DisplayUnit<'a> {
+    pub(crate) fn new(index: usize, pages: &'a [Page], direction: Direction) -> Self {
+        DisplayUnit {
+            index,
+
+            pages,
+
+            direction,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn pages(&self) -> &'a [Page] {
+        self.pages
+    }
+
+    pub fn is_spread(&self) -> bool {
+        self.pages.len() > 1
+    }
+
+    pub fn layout(&self) -> Vec<&'a Page> {
+        match self.direction {
+            Direction::RightToLeft | Direction::Vertical => self.pages.iter().rev().collect(),
+
+            Direction::LeftToRight => self.pages.iter().collect(),
+        }
+    }
+}
+
+pub(crate) fn units(pages: &[Page], direction: Direction) -> Vec<DisplayUnit<'_>> {
+    let mut index = 0;
+
+    pages
+        .chunk_by(|a, b| a.spread().is_some() && a.spread() == b.spread())
+        .map(|pages| {
+            let unit = DisplayUnit::new(index, pages, direction);
+
+            index += pages.len();
+
+            unit
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Direction;
+    use crate::Page;
+
+    fn labels(pages: Vec<&Page>) -> Vec<&str> {
+        pages.into_iter().map(|page| page.label()).collect()
+    }
+
+    #[test]
+    fn units() {
+        let pages = [
+            Page::new().with_label("1").with_spread(0),
+            Page::new().with_label("2"),
+            Page::new().with_label("3").with_spread(0),
+            Page::new().with_label("4").with_spread(0),
+        ];
+
+        let units = super::units(&pages, Direction::LeftToRight);
+
+        assert_eq!(units.len(), 3);
+
+        assert!(!units[0].is_spread());
+        assert!(!units[1].is_spread());
+        assert!(units[2].is_spread());
+
+        assert_eq!(units[2].index(), 2);
+        assert_eq!(labels(units[2].layout()), vec!["3", "4"]);
+    }
+
+    #[test]
+    fn vertical() {
+        let pages = [
+            Page::new().with_label("1").with_spread(0),
+            Page::new().with_label("2").with_spread(0),
+        ];
+
+        let units = super::units(&pages, Direction::Vertical);
+
+        assert_eq!(units.len(), 1);
+        assert_eq!(labels(units[0].layout()), vec!["2", "1"]);
+    }
+}
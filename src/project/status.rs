@@ -0,0 +1,68 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Status {
+    #[default]
+    Untranslated,
+    Translated,
+    Proofread,
+    Typeset,
+    Done,
+}
+
+impl Status {
+    pub const ALL: [Status; 5] = [
+        Status::Untranslated,
+        Status::Translated,
+        Status::Proofread,
+        Status::Typeset,
+        Status::Done,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Untranslated => "untranslated",
+            Status::Translated => "translated",
+            Status::Proofread => "proofread",
+            Status::Typeset => "typeset",
+            Status::Done => "done",
+        }
+    }
+}
+
+impl Codec for Status {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Status::ALL
+            .into_iter()
+            .find(|status| reader.value().as_str() == Some(status.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "a status".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Status {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Status::ALL.map(|status| status.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
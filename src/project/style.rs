@@ -0,0 +1,389 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Style {
+    font: Option<String>,
+    size: Option<f32>,
+    color: Option<String>,
+
+    stroke: Option<String>,
+    stroke_width: Option<f32>,
+
+    alignment: Option<Alignment>,
+    orientation: Option<Orientation>,
+}
+
+impl Alignment {
+    pub const ALL: [Alignment; 3] = [Alignment::Left, Alignment::Center, Alignment::Right];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alignment::Left => "left",
+            Alignment::Center => "center",
+            Alignment::Right => "right",
+        }
+    }
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 2] = [Orientation::Horizontal, Orientation::Vertical];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        }
+    }
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Style::default()
+    }
+
+    pub fn font(&self) -> Option<&str> {
+        self.font.as_deref()
+    }
+
+    pub fn set_font(&mut self, font: Option<String>) {
+        self.font = font;
+    }
+
+    pub fn with_font<F>(mut self, font: F) -> Self
+    where
+        F: ToString,
+    {
+        self.font = Some(font.to_string());
+
+        self
+    }
+
+    pub fn size(&self) -> Option<f32> {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: Option<f32>) {
+        self.size = size;
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+
+        self
+    }
+
+    pub fn color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    pub fn set_color(&mut self, color: Option<String>) {
+        self.color = color;
+    }
+
+    pub fn with_color<C>(mut self, color: C) -> Self
+    where
+        C: ToString,
+    {
+        self.color = Some(color.to_string());
+
+        self
+    }
+
+    pub fn stroke(&self) -> Option<&str> {
+        self.stroke.as_deref()
+    }
+
+    pub fn set_stroke(&mut self, stroke: Option<String>) {
+        self.stroke = stroke;
+    }
+
+    pub fn with_stroke<C>(mut self, stroke: C) -> Self
+    where
+        C: ToString,
+    {
+        self.stroke = Some(stroke.to_string());
+
+        self
+    }
+
+    pub fn stroke_width(&self) -> Option<f32> {
+        self.stroke_width
+    }
+
+    pub fn set_stroke_width(&mut self, stroke_width: Option<f32>) {
+        self.stroke_width = stroke_width;
+    }
+
+    pub fn with_stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = Some(stroke_width);
+
+        self
+    }
+
+    pub fn alignment(&self) -> Option<Alignment> {
+        self.alignment
+    }
+
+    pub fn set_alignment(&mut self, alignment: Option<Alignment>) {
+        self.alignment = alignment;
+    }
+
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+
+        self
+    }
+
+    pub fn orientation(&self) -> Option<Orientation> {
+        self.orientation
+    }
+
+    pub fn set_orientation(&mut self, orientation: Option<Orientation>) {
+        self.orientation = orientation;
+    }
+
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+
+        self
+    }
+
+    pub fn merge(&self, overrides: &Style) -> Style {
+        Style {
+            font: overrides.font.clone().or_else(|| self.font.clone()),
+            size: overrides.size.or(self.size),
+            color: overrides.color.clone().or_else(|| self.color.clone()),
+
+            stroke: overrides.stroke.clone().or_else(|| self.stroke.clone()),
+            stroke_width: overrides.stroke_width.or(self.stroke_width),
+
+            alignment: overrides.alignment.or(self.alignment),
+            orientation: overrides.orientation.or(self.orientation),
+        }
+    }
+}
+
+impl Codec for Alignment {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Alignment::ALL
+            .into_iter()
+            .find(|alignment| reader.value().as_str() == Some(alignment.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "an alignment".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Alignment {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Alignment::ALL.map(|alignment| alignment.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Codec for Orientation {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Orientation::ALL
+            .into_iter()
+            .find(|orientation| reader.value().as_str() == Some(orientation.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "an orientation".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Orientation {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Orientation::ALL.map(|orientation| orientation.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Codec for Style {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.value(json!({}));
+
+                if self.font.is_some() {
+                    writer.field("font", &self.font)?;
+                }
+
+                if self.size.is_some() {
+                    writer.field("size", &self.size)?;
+                }
+
+                if self.color.is_some() {
+                    writer.field("color", &self.color)?;
+                }
+
+                if self.stroke.is_some() {
+                    writer.field("stroke", &self.stroke)?;
+                }
+
+                if self.stroke_width.is_some() {
+                    writer.field("stroke_width", &self.stroke_width)?;
+                }
+
+                if self.alignment.is_some() {
+                    writer.field("alignment", &self.alignment)?;
+                }
+
+                if self.orientation.is_some() {
+                    writer.field("orientation", &self.orientation)?;
+                }
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => Ok(Style {
+                font: reader.field_or_default("font")?,
+                size: reader.field_or_default("size")?,
+                color: reader.field_or_default("color")?,
+
+                stroke: reader.field_or_default("stroke")?,
+                stroke_width: reader.field_or_default("stroke_width")?,
+
+                alignment: reader.field_or_default("alignment")?,
+                orientation: reader.field_or_default("orientation")?,
+            }),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Style {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "font": Option::<String>::schema(version)?,
+                    "size": Option::<f32>::schema(version)?,
+                    "color": Option::<String>::schema(version)?,
+                    "stroke": Option::<String>::schema(version)?,
+                    "stroke_width": Option::<f32>::schema(version)?,
+                    "alignment": Option::<Alignment>::schema(version)?,
+                    "orientation": Option::<Orientation>::schema(version)?,
+                },
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Alignment;
+    use crate::Codec;
+    use crate::Orientation;
+    use crate::Style;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use serde_json::json;
+
+    #[test]
+    fn merge() {
+        let base = Style::new()
+            .with_font("Wild Words")
+            .with_size(24.0)
+            .with_alignment(Alignment::Center);
+
+        let style = base.merge(&Style::new().with_size(36.0).with_color("#ff0000"));
+
+        assert_eq!(style.font(), Some("Wild Words"));
+        assert_eq!(style.size(), Some(36.0));
+        assert_eq!(style.color(), Some("#ff0000"));
+        assert_eq!(style.alignment(), Some(Alignment::Center));
+        assert_eq!(style.orientation(), None);
+
+        assert!(Style::new().is_empty());
+        assert!(!style.is_empty());
+    }
+
+    #[test]
+    fn encode() {
+        let style = Style::new()
+            .with_font("Wild Words")
+            .with_stroke("#ffffff")
+            .with_stroke_width(2.0)
+            .with_orientation(Orientation::Vertical);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&style, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "font": "Wild Words",
+                "stroke": "#ffffff",
+                "stroke_width": 2.0,
+                "orientation": "vertical"
+            })
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use crate::project::Status;
+use serde_json::Value;
+use serde_json::json;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Translation,
+    Proofreading,
+    Typesetting,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Member {
+    name: String,
+
+    stages: Vec<Stage>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    member: String,
+
+    stage: Stage,
+
+    pages: Range<usize>,
+
+    due: Option<u64>,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Translation, Stage::Proofreading, Stage::Typesetting];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Translation => "translation",
+            Stage::Proofreading => "proofreading",
+            Stage::Typesetting => "typesetting",
+        }
+    }
+
+    pub fn target(&self) -> Status {
+        match self {
+            Stage::Translation => Status::Translated,
+            Stage::Proofreading => Status::Proofread,
+            Stage::Typesetting => Status::Typeset,
+        }
+    }
+}
+
+impl Member {
+    pub fn new<N>(name: N) -> Self
+    where
+        N: ToString,
+    {
+        Member {
+            name: name.to_string(),
+
+            stages: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn stages(&self) -> &Vec<Stage> {
+        &self.stages
+    }
+
+    pub fn stages_mut(&mut self) -> &mut Vec<Stage> {
+        &mut self.stages
+    }
+
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        if !self.stages.contains(&stage) {
+            self.stages.push(stage);
+        }
+
+        self
+    }
+}
+
+impl Assignment {
+    pub fn new<M>(member: M, stage: Stage, pages: Range<usize>) -> Self
+    where
+        M: ToString,
+    {
+        Assignment {
+            member: member.to_string(),
+
+            stage,
+
+            pages,
+
+            due: None,
+        }
+    }
+
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    pub fn pages(&self) -> Range<usize> {
+        self.pages.clone()
+    }
+
+    pub fn due(&self) -> Option<u64> {
+        self.due
+    }
+
+    pub fn set_due(&mut self, due: Option<u64>) {
+        self.due = due;
+    }
+
+    pub fn with_due(mut self, due: u64) -> Self {
+        self.due = Some(due);
+
+        self
+    }
+
+    pub(crate) fn insert_page(&mut self, index: usize) {
+        if self.pages.start >= index {
+            self.pages = self.pages.start + 1..self.pages.end + 1;
+        } else if self.pages.end > index {
+            self.pages.end += 1;
+        }
+    }
+
+    pub(crate) fn remove_page(&mut self, index: usize) {
+        if self.pages.start > index {
+            self.pages.start -= 1;
+        }
+
+        if self.pages.end > index {
+            self.pages.end -= 1;
+        }
+    }
+}
+
+impl Codec for Stage {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        writer.value(self.as_str());
+
+        Ok(())
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        Stage::ALL
+            .into_iter()
+            .find(|stage| reader.value().as_str() == Some(stage.as_str()))
+            .ok_or(codec::Error::MismatchType {
+                expected: "a stage".to_string(),
+                found: reader.value().to_string(),
+            })
+    }
+}
+
+impl Schema for Stage {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "string",
+                "enum": Stage::ALL.map(|stage| stage.as_str()),
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Codec for Member {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.field("name", &self.name)?;
+
+                writer.field("stages", &self.stages)?;
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => Ok(Member {
+                name: reader.field("name")?,
+
+                stages: reader.field("stages")?,
+            }),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Member {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "name": String::schema(version)?,
+                    "stages": Vec::<Stage>::schema(version)?,
+                },
+                "required": ["name", "stages"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Codec for Assignment {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.field("member", &self.member)?;
+
+                writer.field("stage", &self.stage)?;
+
+                writer.field("start", &(self.pages.start as u64))?;
+                writer.field("end", &(self.pages.end as u64))?;
+
+                if self.due.is_some() {
+                    writer.field("due", &self.due)?;
+                }
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => {
+                let start = reader.field::<_, u64>("start")?;
+                let end = reader.field::<_, u64>("end")?;
+
+                if start > end {
+                    return Err(codec::Error::MismatchType {
+                        expected: format!("an end no less than {}", start),
+                        found: end.to_string(),
+                    });
+                }
+
+                Ok(Assignment {
+                    member: reader.field("member")?,
+
+                    stage: reader.field("stage")?,
+
+                    pages: start as usize..end as usize,
+
+                    due: reader.field_or_default("due")?,
+                })
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Assignment {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "member": String::schema(version)?,
+                    "stage": Stage::schema(version)?,
+                    "start": u64::schema(version)?,
+                    "end": u64::schema(version)?,
+                    "due": Option::<u64>::schema(version)?,
+                },
+                "required": ["member", "stage", "start", "end"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assignment;
+    use crate::Codec;
+    use crate::Stage;
+    use crate::codec;
+    use crate::codec::Reader;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use crate::source::EmptySource;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn encode() {
+        let assignment =
+            Assignment::new("translator", Stage::Translation, 0..4).with_due(1_700_000_000);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&assignment, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "member": "translator",
+                "stage": "translation",
+                "start": 0,
+                "end": 4,
+                "due": 1_700_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let manifest = Manifest::default();
+
+        let value = json!({ "member": "translator", "stage": "translation", "start": 2, "end": 4 });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Assignment::decode(&reader) {
+            Ok(assignment) => assert_eq!(assignment.pages(), 2..4),
+            Err(err) => panic!("Failed to decode assignment: {:?}", err),
+        }
+
+        let value = json!({ "member": "translator", "stage": "translation", "start": 4, "end": 2 });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        assert!(matches!(
+            Assignment::decode(&reader),
+            Err(codec::Error::MismatchType { .. })
+        ));
+    }
+}
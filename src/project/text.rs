@@ -1,12 +1,45 @@
 use crate::Codec;
 use crate::codec;
 use crate::codec::Reader;
+use crate::codec::Schema;
 use crate::codec::Writer;
-
-#[derive(Debug, Default, Clone)]
+use crate::project::Extensions;
+use crate::project::Remark;
+use crate::project::Review;
+use crate::project::Role;
+use crate::project::Session;
+use crate::project::Span;
+use crate::project::Style;
+use crate::project::Thread;
+use crate::project::session;
+use crate::project::span;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
 pub struct Text {
     content: String,
+    spans: Vec<Span>,
     comment: String,
+
+    language: String,
+    role: Role,
+
+    author: String,
+    created: Option<u64>,
+    modified: Option<u64>,
+
+    review: Review,
+    remarks: Vec<Remark>,
+
+    overrides: Style,
+
+    threads: Vec<Thread>,
+
+    extensions: Extensions,
+
+    session: Option<Arc<Session>>,
 }
 
 impl Text {
@@ -23,6 +56,11 @@ impl Text {
         C: ToString,
     {
         self.content = content.to_string();
+        self.spans.clear();
+
+        self.review = Review::Pending;
+
+        self.touch();
     }
 
     pub fn with_content<C>(mut self, content: C) -> Self
@@ -30,10 +68,35 @@ impl Text {
         C: ToString,
     {
         self.content = content.to_string();
+        self.spans.clear();
+
+        self
+    }
+
+    pub fn spans(&self) -> &Vec<Span> {
+        &self.spans
+    }
+
+    pub fn set_spans(&mut self, spans: Vec<Span>) {
+        self.content = span::plain(&spans);
+        self.spans = spans;
+
+        self.review = Review::Pending;
+
+        self.touch();
+    }
+
+    pub fn with_spans(mut self, spans: Vec<Span>) -> Self {
+        self.content = span::plain(&spans);
+        self.spans = spans;
 
         self
     }
 
+    pub fn is_rich(&self) -> bool {
+        !self.spans.is_empty()
+    }
+
     pub fn comment(&self) -> &str {
         &self.comment
     }
@@ -43,6 +106,8 @@ impl Text {
         M: ToString,
     {
         self.comment = comment.to_string();
+
+        self.touch();
     }
 
     pub fn with_comment<M>(mut self, comment: M) -> Self
@@ -53,6 +118,224 @@ impl Text {
 
         self
     }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn set_language<L>(&mut self, language: L)
+    where
+        L: ToString,
+    {
+        self.language = language.to_string();
+
+        self.touch();
+    }
+
+    pub fn with_language<L>(mut self, language: L) -> Self
+    where
+        L: ToString,
+    {
+        self.language = language.to_string();
+
+        self
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn set_role(&mut self, role: Role) {
+        self.role = role;
+
+        self.touch();
+    }
+
+    pub fn with_role(mut self, role: Role) -> Self {
+        self.role = role;
+
+        self
+    }
+
+    pub fn is_source(&self) -> bool {
+        self.role == Role::Source
+    }
+
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn set_author<A>(&mut self, author: A)
+    where
+        A: ToString,
+    {
+        self.author = author.to_string();
+    }
+
+    pub fn with_author<A>(mut self, author: A) -> Self
+    where
+        A: ToString,
+    {
+        self.author = author.to_string();
+
+        self
+    }
+
+    pub fn created(&self) -> Option<u64> {
+        self.created
+    }
+
+    pub fn set_created(&mut self, created: Option<u64>) {
+        self.created = created;
+    }
+
+    pub fn modified(&self) -> Option<u64> {
+        self.modified
+    }
+
+    pub fn set_modified(&mut self, modified: Option<u64>) {
+        self.modified = modified;
+    }
+
+    pub fn review(&self) -> Review {
+        self.review
+    }
+
+    pub fn set_review(&mut self, review: Review) {
+        self.review = review;
+    }
+
+    pub fn with_review(mut self, review: Review) -> Self {
+        self.review = review;
+
+        self
+    }
+
+    pub fn remarks(&self) -> &Vec<Remark> {
+        &self.remarks
+    }
+
+    pub fn remarks_mut(&mut self) -> &mut Vec<Remark> {
+        &mut self.remarks
+    }
+
+    pub fn with_remark(mut self, remark: Remark) -> Self {
+        self.remarks.push(remark);
+
+        self
+    }
+
+    pub fn add_remark<C>(&mut self, content: C) -> &mut Remark
+    where
+        C: ToString,
+    {
+        let mut remark = Remark::new(content);
+
+        if let Some(identity) = self.session.as_ref().and_then(|session| session.identity()) {
+            remark.set_author(identity);
+            remark.set_created(Some(session::now()));
+        }
+
+        self.remarks.push(remark);
+
+        let index = self.remarks.len() - 1;
+
+        &mut self.remarks[index]
+    }
+
+    pub fn overrides(&self) -> &Style {
+        &self.overrides
+    }
+
+    pub fn overrides_mut(&mut self) -> &mut Style {
+        &mut self.overrides
+    }
+
+    pub fn with_overrides(mut self, overrides: Style) -> Self {
+        self.overrides = overrides;
+
+        self
+    }
+
+    pub fn threads(&self) -> &Vec<Thread> {
+        &self.threads
+    }
+
+    pub fn threads_mut(&mut self) -> &mut Vec<Thread> {
+        &mut self.threads
+    }
+
+    pub fn with_thread(mut self, thread: Thread) -> Self {
+        self.threads.push(thread);
+
+        self
+    }
+
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    pub fn with_extension<N, K, V>(mut self, namespace: N, key: K, value: V) -> Self
+    where
+        N: ToString,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.extensions.insert(namespace, key, value);
+
+        self
+    }
+
+    pub(crate) fn attach(&mut self, session: &Arc<Session>) {
+        self.session = Some(Arc::clone(session));
+    }
+
+    fn touch(&mut self) {
+        let Some(identity) = self.session.as_ref().and_then(|session| session.identity()) else {
+            return;
+        };
+
+        let now = session::now();
+
+        if self.author.is_empty() {
+            self.author = identity;
+        }
+
+        self.created.get_or_insert(now);
+        self.modified = Some(now);
+    }
+}
+
+impl Clone for Text {
+    fn clone(&self) -> Self {
+        Text {
+            content: self.content.clone(),
+            spans: self.spans.clone(),
+            comment: self.comment.clone(),
+
+            language: self.language.clone(),
+            role: self.role,
+
+            author: self.author.clone(),
+            created: self.created,
+            modified: self.modified,
+
+            review: self.review,
+            remarks: self.remarks.clone(),
+
+            overrides: self.overrides.clone(),
+
+            threads: self.threads.clone(),
+
+            extensions: self.extensions.clone(),
+
+            session: None,
+        }
+    }
 }
 
 impl Codec for Text {
@@ -61,8 +344,52 @@ impl Codec for Text {
             0 => {
                 writer.field("content", &self.content)?;
 
+                if !self.spans.is_empty() {
+                    writer.field("spans", &self.spans)?;
+                }
+
                 writer.field("comment", &self.comment)?;
 
+                if !self.language.is_empty() {
+                    writer.field("language", &self.language)?;
+                }
+
+                if self.role != Role::default() {
+                    writer.field("role", &self.role)?;
+                }
+
+                if !self.author.is_empty() {
+                    writer.field("author", &self.author)?;
+                }
+
+                if self.created.is_some() {
+                    writer.field("created", &self.created)?;
+                }
+
+                if self.modified.is_some() {
+                    writer.field("modified", &self.modified)?;
+                }
+
+                if self.review != Review::default() {
+                    writer.field("review", &self.review)?;
+                }
+
+                if !self.remarks.is_empty() {
+                    writer.field("remarks", &self.remarks)?;
+                }
+
+                if !self.overrides.is_empty() {
+                    writer.field("overrides", &self.overrides)?;
+                }
+
+                if !self.threads.is_empty() {
+                    writer.field("threads", &self.threads)?;
+                }
+
+                if !self.extensions.is_empty() {
+                    writer.field("extensions", &self.extensions)?;
+                }
+
                 Ok(())
             }
 
@@ -72,11 +399,69 @@ impl Codec for Text {
 
     fn decode(reader: &Reader) -> codec::Result<Self> {
         match reader.manifest().version() {
-            0 => Ok(Text {
-                content: reader.field("content")?,
+            0 => {
+                let content = reader.field::<_, String>("content")?;
+                let mut spans = reader.field_or_default::<_, Vec<Span>>("spans")?;
+
+                if span::plain(&spans) != content {
+                    spans.clear();
+                }
+
+                Ok(Text {
+                    content,
+                    spans,
+
+                    comment: reader.field("comment")?,
+
+                    language: reader.field_or_default("language")?,
+
+                    role: reader.field_or_default("role")?,
+
+                    author: reader.field_or_default("author")?,
+                    created: reader.field_or_default("created")?,
+                    modified: reader.field_or_default("modified")?,
+
+                    review: reader.field_or_default("review")?,
+                    remarks: reader.field_or_default("remarks")?,
+
+                    overrides: reader.field_or_default("overrides")?,
+
+                    threads: reader.field_or_default("threads")?,
+
+                    extensions: reader.field_or_default("extensions")?,
+
+                    session: Some(reader.session()),
+                })
+            }
 
-                comment: reader.field("comment")?,
-            }),
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Text {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "content": String::schema(version)?,
+                    "spans": Vec::<Span>::schema(version)?,
+                    "comment": String::schema(version)?,
+                    "language": String::schema(version)?,
+                    "role": Role::schema(version)?,
+                    "author": String::schema(version)?,
+                    "created": Option::<u64>::schema(version)?,
+                    "modified": Option::<u64>::schema(version)?,
+                    "review": Review::schema(version)?,
+                    "remarks": Vec::<Remark>::schema(version)?,
+                    "overrides": Style::schema(version)?,
+                    "threads": Vec::<Thread>::schema(version)?,
+
+                    "extensions": Extensions::schema(version)?,
+                },
+                "required": ["content", "comment"],
+            })),
 
             version => Err(codec::Error::UnsupportedVersion { version }),
         }
@@ -86,10 +471,17 @@ impl Codec for Text {
 #[cfg(test)]
 mod tests {
     use crate::Codec;
+    use crate::Remark;
+    use crate::Review;
+    use crate::Role;
+    use crate::Span;
     use crate::Text;
+    use crate::codec::Reader;
     use crate::codec::Writer;
     use crate::file::Manifest;
+    use crate::source::EmptySource;
     use serde_json::json;
+    use std::sync::Arc;
 
     #[test]
     fn new() {
@@ -132,6 +524,27 @@ mod tests {
         assert_eq!(text.content(), "This is a content.");
     }
 
+    #[test]
+    fn set_content_review() {
+        let mut text = Text::new()
+            .with_content("This is a content.")
+            .with_review(Review::Approved);
+
+        text.set_comment("This is a comment.");
+
+        assert_eq!(text.review(), Review::Approved);
+
+        text.set_content("This is a revised content.");
+
+        assert_eq!(text.review(), Review::Pending);
+
+        text.set_review(Review::Approved);
+
+        text.set_spans(vec![Span::run("This is a span.")]);
+
+        assert_eq!(text.review(), Review::Pending);
+    }
+
     #[test]
     fn set_comment() {
         let mut text = Text::new();
@@ -163,4 +576,182 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn with_extension() {
+        let text = Text::new()
+            .with_content("This is a content.")
+            .with_extension("com.example.ocr", "confidence", 0.75);
+
+        assert_eq!(
+            text.extensions().get("com.example.ocr", "confidence"),
+            Some(&json!(0.75))
+        );
+    }
+
+    #[test]
+    fn encode_extensions() {
+        let text = Text::new()
+            .with_content("This is a content.")
+            .with_extension("com.example.ocr", "confidence", 0.75);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&text, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "content": "This is a content.",
+                "comment": "",
+                "extensions": {
+                    "com.example.ocr": {
+                        "confidence": 0.75
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn encode_language() {
+        let text = Text::new()
+            .with_content("ドカーン")
+            .with_language("ja")
+            .with_role(Role::Source);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&text, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "content": "ドカーン",
+                "comment": "",
+                "language": "ja",
+                "role": "source"
+            })
+        );
+    }
+
+    #[test]
+    fn encode_authorship() {
+        let mut text = Text::new()
+            .with_content("This is a content.")
+            .with_author("translator");
+
+        text.set_created(Some(1_700_000_000));
+        text.set_modified(Some(1_700_000_060));
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&text, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "content": "This is a content.",
+                "comment": "",
+                "author": "translator",
+                "created": 1_700_000_000,
+                "modified": 1_700_000_060
+            })
+        );
+    }
+
+    #[test]
+    fn encode_review() {
+        let text = Text::new()
+            .with_content("This is a content.")
+            .with_review(Review::NeedsChanges)
+            .with_remark(Remark::new("Too literal.").with_author("proofreader"));
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&text, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "content": "This is a content.",
+                "comment": "",
+                "review": "needs_changes",
+                "remarks": [
+                    {
+                        "author": "proofreader",
+                        "content": "Too literal."
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn with_spans() {
+        let mut text = Text::new().with_spans(vec![
+            Span::ruby("東京", "とうきょう"),
+            Span::line_break(),
+            Span::run("GO!").with_bold(true),
+        ]);
+
+        assert!(text.is_rich());
+        assert_eq!(text.content(), "東京\nGO!");
+
+        text.set_content("Tokyo");
+
+        assert!(!text.is_rich());
+        assert_eq!(text.content(), "Tokyo");
+    }
+
+    #[test]
+    fn decode_spans() {
+        let manifest = Manifest::default();
+
+        let value = json!({
+            "content": "Hey!",
+            "comment": "",
+            "spans": [{ "type": "run", "content": "Hey!", "bold": true }],
+        });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Text::decode(&reader) {
+            Ok(text) => assert!(text.is_rich()),
+            Err(err) => panic!("Failed to decode text: {:?}", err),
+        }
+
+        let value = json!({
+            "content": "Hey, you!",
+            "comment": "",
+            "spans": [{ "type": "run", "content": "Hey!", "bold": true }],
+        });
+
+        let reader = Reader::new(&manifest, &value, Arc::new(EmptySource));
+
+        match Text::decode(&reader) {
+            Ok(text) => {
+                assert_eq!(text.content(), "Hey, you!");
+                assert!(!text.is_rich());
+            }
+
+            Err(err) => panic!("Failed to decode text: {:?}", err),
+        }
+    }
 }
@@ -0,0 +1,186 @@
+use crate::Codec;
+use crate::codec;
+use crate::codec::Reader;
+use crate::codec::Schema;
+use crate::codec::Writer;
+use crate::project::Comment;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Thread {
+    comment: Comment,
+
+    replies: Vec<Comment>,
+
+    resolved: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    page: usize,
+
+    note: Option<usize>,
+
+    text: Option<usize>,
+}
+
+impl Thread {
+    pub fn new(comment: Comment) -> Self {
+        Thread {
+            comment,
+
+            ..Self::default()
+        }
+    }
+
+    pub fn comment(&self) -> &Comment {
+        &self.comment
+    }
+
+    pub fn comment_mut(&mut self) -> &mut Comment {
+        &mut self.comment
+    }
+
+    pub fn replies(&self) -> &Vec<Comment> {
+        &self.replies
+    }
+
+    pub fn replies_mut(&mut self) -> &mut Vec<Comment> {
+        &mut self.replies
+    }
+
+    pub fn reply(&mut self, reply: Comment) {
+        self.replies.push(reply);
+    }
+
+    pub fn with_reply(mut self, reply: Comment) -> Self {
+        self.replies.push(reply);
+
+        self
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.resolved
+    }
+
+    pub fn set_resolved(&mut self, resolved: bool) {
+        self.resolved = resolved;
+    }
+
+    pub fn with_resolved(mut self, resolved: bool) -> Self {
+        self.resolved = resolved;
+
+        self
+    }
+}
+
+impl Location {
+    pub(crate) fn new(page: usize, note: Option<usize>, text: Option<usize>) -> Self {
+        Location { page, note, text }
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn note(&self) -> Option<usize> {
+        self.note
+    }
+
+    pub fn text(&self) -> Option<usize> {
+        self.text
+    }
+}
+
+impl Codec for Thread {
+    fn encode(&self, writer: &mut Writer) -> codec::Result<()> {
+        match writer.manifest().version() {
+            0 => {
+                writer.field("comment", &self.comment)?;
+
+                writer.field("replies", &self.replies)?;
+
+                writer.field("resolved", &self.resolved)?;
+
+                Ok(())
+            }
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+
+    fn decode(reader: &Reader) -> codec::Result<Self> {
+        match reader.manifest().version() {
+            0 => Ok(Thread {
+                comment: reader.field("comment")?,
+
+                replies: reader.field("replies")?,
+
+                resolved: reader.field("resolved")?,
+            }),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+impl Schema for Thread {
+    fn schema(version: u8) -> codec::Result<Value> {
+        match version {
+            0 => Ok(json!({
+                "type": "object",
+                "properties": {
+                    "comment": Comment::schema(version)?,
+                    "replies": Vec::<Comment>::schema(version)?,
+                    "resolved": bool::schema(version)?,
+                },
+                "required": ["comment", "replies", "resolved"],
+            })),
+
+            version => Err(codec::Error::UnsupportedVersion { version }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Codec;
+    use crate::Comment;
+    use crate::Thread;
+    use crate::codec::Writer;
+    use crate::file::Manifest;
+    use serde_json::json;
+
+    #[test]
+    fn encode() {
+        let thread = Thread::new(Comment::new("Is this a pun?").with_author("typesetter"))
+            .with_reply(Comment::new("Yes, keep both readings.").with_author("translator"))
+            .with_resolved(true);
+
+        let manifest = Manifest::default();
+
+        let mut writer = Writer::new(&manifest);
+
+        assert!(Codec::encode(&thread, &mut writer).is_ok());
+
+        let (_, value) = writer.end();
+
+        assert_eq!(
+            value,
+            json!({
+                "comment": {
+                    "author": "typesetter",
+                    "content": "Is this a pun?"
+                },
+                "replies": [
+                    {
+                        "author": "translator",
+                        "content": "Yes, keep both readings."
+                    }
+                ],
+                "resolved": true
+            })
+        );
+    }
+}
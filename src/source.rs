@@ -0,0 +1,22 @@
+mod archive;
+mod cache;
+mod directory;
+mod empty;
+mod file;
+mod memory;
+mod overlay;
+mod shared;
+
+pub use crate::codec::AssetSize;
+pub use crate::codec::AssetSource;
+pub use crate::codec::Stream;
+
+pub use archive::ArchiveSource;
+pub use cache::CacheSource;
+pub use directory::DirectorySource;
+pub use empty::EmptySource;
+pub use file::FileSource;
+pub use memory::MemorySource;
+pub use overlay::OverlaySource;
+
+pub(crate) use shared::Shared;
@@ -0,0 +1,251 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use crate::codec::Stream;
+use crate::source::Shared;
+use flate2::read::DeflateDecoder;
+use std::fs::File;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use zip::CompressionMethod;
+use zip::ZipArchive;
+use zip::ZipWriter;
+
+pub struct ArchiveSource {
+    archive: ZipArchive<Shared>,
+}
+
+impl ArchiveSource {
+    pub fn new<R>(stream: R) -> codec::Result<Self>
+    where
+        R: Read + Seek + Send + 'static,
+    {
+        ArchiveSource::from_shared(Shared::new(stream))
+    }
+
+    pub fn from_file(file: File) -> codec::Result<Self> {
+        ArchiveSource::from_shared(Shared::from_file(file))
+    }
+
+    pub(crate) fn from_archive(archive: ZipArchive<Shared>) -> Self {
+        ArchiveSource { archive }
+    }
+
+    fn from_shared(stream: Shared) -> codec::Result<Self> {
+        ZipArchive::new(stream)
+            .map(ArchiveSource::from_archive)
+            .map_err(|_| codec::Error::ArchiveOpenFailed)
+    }
+}
+
+impl AssetSource for ArchiveSource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        let mut archive = self.archive.clone();
+
+        let mut stream = match archive.by_name(path) {
+            Ok(val) => val,
+
+            Err(_) => {
+                return Err(codec::Error::AssetNotFound {
+                    path: path.to_string(),
+                });
+            }
+        };
+
+        let mut data = Vec::new();
+
+        if stream.read_to_end(&mut data).is_err() {
+            return Err(codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            });
+        }
+
+        Ok(data)
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        let mut archive = self.archive.clone();
+
+        let (compression, encrypted, start, size) = match archive.by_name(path) {
+            Ok(val) => (
+                val.compression(),
+                val.encrypted(),
+                val.data_start(),
+                val.compressed_size(),
+            ),
+
+            Err(_) => {
+                return Err(codec::Error::AssetNotFound {
+                    path: path.to_string(),
+                });
+            }
+        };
+
+        let mut stream = archive.into_inner();
+
+        stream
+            .seek(SeekFrom::Start(start))
+            .map_err(|_| codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            })?;
+
+        let stream = stream.take(size);
+
+        match (compression, encrypted) {
+            (CompressionMethod::Stored, false) => Ok(Box::new(stream)),
+            (CompressionMethod::Deflated, false) => Ok(Box::new(DeflateDecoder::new(stream))),
+
+            _ => Ok(Box::new(Cursor::new(self.load(path)?))),
+        }
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.archive.index_for_name(path).is_some()
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        let mut archive = self.archive.clone();
+
+        let index = archive
+            .index_for_name(path)
+            .ok_or(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            })?;
+
+        match archive.by_index_raw(index) {
+            Ok(val) => Ok(AssetSize::new(Some(val.compressed_size()), val.size())),
+
+            Err(_) => Err(codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            }),
+        }
+    }
+
+    fn copy(
+        &self,
+        path: &str,
+        name: &str,
+        writer: &mut ZipWriter<&mut dyn Stream>,
+    ) -> codec::Result<()> {
+        let mut archive = self.archive.clone();
+
+        let stream = match archive.by_name(path) {
+            Ok(val) => val,
+
+            Err(_) => {
+                return Err(codec::Error::AssetNotFound {
+                    path: path.to_string(),
+                });
+            }
+        };
+
+        writer
+            .raw_copy_file_rename(stream, name)
+            .map_err(|_| codec::Error::AssetCopyFailed {
+                path: path.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::ArchiveSource;
+    use crate::source::AssetSource;
+    use std::io::Cursor;
+    use std::io::Read;
+    use std::io::Write;
+    use std::thread;
+    use zip::CompressionMethod;
+    use zip::ZipWriter;
+    use zip::write::FileOptions;
+
+    fn archive() -> Vec<u8> {
+        let mut stream = Cursor::new(Vec::new());
+
+        let mut writer = ZipWriter::new(&mut stream);
+
+        for (path, method) in [
+            ("pages/page_1.webp", CompressionMethod::Stored),
+            ("pages/page_2.webp", CompressionMethod::Deflated),
+        ] {
+            let options = FileOptions::<()>::default().compression_method(method);
+
+            if let Err(err) = writer.start_file(path, options) {
+                panic!("Failed to start file: {:?}", err);
+            }
+
+            if let Err(err) = writer.write_all(&[path.len() as u8; 4096]) {
+                panic!("Failed to write file: {:?}", err);
+            }
+        }
+
+        if let Err(err) = writer.finish() {
+            panic!("Failed to finish archive: {:?}", err);
+        }
+
+        stream.into_inner()
+    }
+
+    #[test]
+    fn open() {
+        let source = match ArchiveSource::new(Cursor::new(archive())) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open archive: {:?}", err),
+        };
+
+        for path in ["pages/page_1.webp", "pages/page_2.webp"] {
+            let mut data = Vec::new();
+
+            match source.open(path) {
+                Ok(mut stream) => {
+                    if let Err(err) = stream.read_to_end(&mut data) {
+                        panic!("Failed to read asset: {:?}", err);
+                    }
+                }
+
+                Err(err) => panic!("Failed to open asset: {:?}", err),
+            }
+
+            assert_eq!(data, vec![path.len() as u8; 4096]);
+
+            match source.load(path) {
+                Ok(val) => assert_eq!(val, data),
+                Err(err) => panic!("Failed to load asset: {:?}", err),
+            }
+        }
+
+        assert!(source.open("pages/page_3.webp").is_err());
+    }
+
+    #[test]
+    fn load_parallel() {
+        let mut file = match tempfile::tempfile() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create file: {:?}", err),
+        };
+
+        if let Err(err) = file.write_all(&archive()) {
+            panic!("Failed to write archive: {:?}", err);
+        }
+
+        let source = match ArchiveSource::from_file(file) {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to open archive: {:?}", err),
+        };
+
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for path in ["pages/page_1.webp", "pages/page_2.webp"] {
+                        match source.load(path) {
+                            Ok(data) => assert_eq!(data, vec![path.len() as u8; 4096]),
+                            Err(err) => panic!("Failed to load asset: {:?}", err),
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,118 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use crate::codec::Stream;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+use std::sync::RwLock;
+use zip::ZipWriter;
+
+pub struct CacheSource {
+    inner: Arc<dyn AssetSource>,
+
+    cache: RwLock<HashMap<String, Arc<[u8]>>>,
+}
+
+impl CacheSource {
+    pub fn new(inner: Arc<dyn AssetSource>) -> Self {
+        CacheSource {
+            inner,
+
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut cache) = self.cache.write() {
+            cache.clear();
+        }
+    }
+}
+
+impl AssetSource for CacheSource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        if let Some(data) = self
+            .cache
+            .read()
+            .map_err(|_| codec::Error::AssetAccessFailed {
+                path: path.to_string(),
+            })?
+            .get(path)
+        {
+            return Ok(data.to_vec());
+        }
+
+        let data = self.inner.load(path)?;
+
+        self.cache
+            .write()
+            .map_err(|_| codec::Error::AssetAccessFailed {
+                path: path.to_string(),
+            })?
+            .insert(path.to_string(), Arc::from(data.as_slice()));
+
+        Ok(data)
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        if let Some(data) = self
+            .cache
+            .read()
+            .map_err(|_| codec::Error::AssetAccessFailed {
+                path: path.to_string(),
+            })?
+            .get(path)
+        {
+            return Ok(Box::new(Cursor::new(Arc::clone(data))));
+        }
+
+        self.inner.open(path)
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.cache
+            .read()
+            .is_ok_and(|cache| cache.contains_key(path))
+            || self.inner.contains(path)
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        self.inner.size(path)
+    }
+
+    fn copy(
+        &self,
+        path: &str,
+        name: &str,
+        writer: &mut ZipWriter<&mut dyn Stream>,
+    ) -> codec::Result<()> {
+        self.inner.copy(path, name, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::AssetSource;
+    use crate::source::CacheSource;
+    use crate::source::MemorySource;
+    use std::sync::Arc;
+
+    #[test]
+    fn load() {
+        let inner = MemorySource::new().with_asset("pages/page_1.webp", vec![0, 1, 2, 3]);
+
+        let source = CacheSource::new(Arc::new(inner));
+
+        for _ in 0..2 {
+            match source.load("pages/page_1.webp") {
+                Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+                Err(err) => panic!("Failed to load asset: {:?}", err),
+            }
+        }
+
+        assert!(source.contains("pages/page_1.webp"));
+        assert!(source.load("pages/page_2.webp").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use std::fs::File;
+use std::io::Read;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub struct DirectorySource {
+    root: PathBuf,
+}
+
+impl DirectorySource {
+    pub fn new<P>(root: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        DirectorySource {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let relative = Path::new(path);
+
+        if relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            Some(self.root.join(relative))
+        } else {
+            None
+        }
+    }
+}
+
+impl AssetSource for DirectorySource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        let file = self.resolve(path).filter(|file| file.is_file()).ok_or(
+            codec::Error::AssetNotFound {
+                path: path.to_string(),
+            },
+        )?;
+
+        std::fs::read(file).map_err(|_| codec::Error::AssetLoadFailed {
+            path: path.to_string(),
+        })
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        let file = self.resolve(path).filter(|file| file.is_file()).ok_or(
+            codec::Error::AssetNotFound {
+                path: path.to_string(),
+            },
+        )?;
+
+        File::open(file)
+            .map(|file| Box::new(file) as Box<dyn Read + Send>)
+            .map_err(|_| codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            })
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.resolve(path).is_some_and(|file| file.is_file())
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        let file = self.resolve(path).filter(|file| file.is_file()).ok_or(
+            codec::Error::AssetNotFound {
+                path: path.to_string(),
+            },
+        )?;
+
+        std::fs::metadata(file)
+            .map(|metadata| AssetSize::new(None, metadata.len()))
+            .map_err(|_| codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::AssetSource;
+    use crate::source::DirectorySource;
+    use tempfile::TempDir;
+
+    #[test]
+    fn load() {
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        if let Err(err) = std::fs::create_dir(root.path().join("pages")) {
+            panic!("Failed to create directory: {:?}", err);
+        }
+
+        if let Err(err) = std::fs::write(root.path().join("pages/page_1.webp"), [0, 1, 2, 3]) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        let source = DirectorySource::new(root.path());
+
+        assert!(source.contains("pages/page_1.webp"));
+        assert!(!source.contains("pages/page_2.webp"));
+        assert!(!source.contains("../pages/page_1.webp"));
+
+        match source.load("pages/page_1.webp") {
+            Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::codec;
+use crate::codec::AssetSource;
+use crate::codec::Stream;
+use zip::ZipWriter;
+
+pub struct EmptySource;
+
+impl AssetSource for EmptySource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        Err(codec::Error::AssetNotFound {
+            path: path.to_string(),
+        })
+    }
+
+    fn contains(&self, _: &str) -> bool {
+        false
+    }
+
+    fn copy(&self, path: &str, _: &str, _: &mut ZipWriter<&mut dyn Stream>) -> codec::Result<()> {
+        Err(codec::Error::AssetNotFound {
+            path: path.to_string(),
+        })
+    }
+}
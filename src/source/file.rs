@@ -0,0 +1,186 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use crate::codec::Stream;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use zip::CompressionMethod;
+use zip::DateTime;
+use zip::ZipWriter;
+use zip::write::FileOptions;
+
+#[derive(Debug)]
+pub struct FileSource {
+    path: String,
+
+    file: PathBuf,
+}
+
+impl FileSource {
+    pub fn new<P, F>(path: P, file: F) -> Self
+    where
+        P: ToString,
+        F: AsRef<Path>,
+    {
+        FileSource {
+            path: path.to_string(),
+
+            file: file.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    fn open_file(&self, path: &str) -> codec::Result<File> {
+        if !self.contains(path) {
+            return Err(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            });
+        }
+
+        File::open(&self.file).map_err(|_| codec::Error::AssetLoadFailed {
+            path: path.to_string(),
+        })
+    }
+}
+
+impl AssetSource for FileSource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        let mut data = Vec::new();
+
+        self.open_file(path)?.read_to_end(&mut data).map_err(|_| {
+            codec::Error::AssetLoadFailed {
+                path: path.to_string(),
+            }
+        })?;
+
+        Ok(data)
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        Ok(Box::new(self.open_file(path)?))
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        path == self.path && self.file.is_file()
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        let metadata =
+            self.open_file(path)?
+                .metadata()
+                .map_err(|_| codec::Error::AssetLoadFailed {
+                    path: path.to_string(),
+                })?;
+
+        Ok(AssetSize::new(None, metadata.len()))
+    }
+
+    fn copy(
+        &self,
+        path: &str,
+        name: &str,
+        writer: &mut ZipWriter<&mut dyn Stream>,
+    ) -> codec::Result<()> {
+        let mut file = self.open_file(path)?;
+
+        let options = FileOptions::<()>::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default());
+
+        writer
+            .start_file(name, options)
+            .map_err(|_| codec::Error::AssetCopyFailed {
+                path: path.to_string(),
+            })?;
+
+        io::copy(&mut file, writer).map_err(|_| codec::Error::AssetCopyFailed {
+            path: path.to_string(),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::AssetSource;
+    use crate::source::FileSource;
+    use crate::source::MemorySource;
+    use crate::source::OverlaySource;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn load() {
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        let file = root.path().join("page_1.webp");
+
+        let source = FileSource::new("pages/page_1.webp", &file);
+
+        assert!(!source.contains("pages/page_1.webp"));
+
+        if let Err(err) = std::fs::write(&file, [0, 1, 2, 3]) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        assert!(source.contains("pages/page_1.webp"));
+        assert!(!source.contains("pages/page_2.webp"));
+
+        assert!(source.load("pages/page_2.webp").is_err());
+
+        match source.load("pages/page_1.webp") {
+            Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        match source.size("pages/page_1.webp") {
+            Ok(size) => assert_eq!(size.uncompressed(), 4),
+            Err(err) => panic!("Failed to read size: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn overlay() {
+        let root = match TempDir::new() {
+            Ok(val) => val,
+            Err(err) => panic!("Failed to create directory: {:?}", err),
+        };
+
+        let file = root.path().join("page_2.webp");
+
+        if let Err(err) = std::fs::write(&file, [4, 5]) {
+            panic!("Failed to write file: {:?}", err);
+        }
+
+        let base = MemorySource::new()
+            .with_asset("pages/page_1.webp", vec![0, 1])
+            .with_asset("pages/page_2.webp", vec![2, 3]);
+
+        let source = OverlaySource::new(Arc::new(base))
+            .with_layer(Arc::new(FileSource::new("pages/page_2.webp", &file)));
+
+        match source.load("pages/page_1.webp") {
+            Ok(data) => assert_eq!(data, vec![0, 1]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        match source.load("pages/page_2.webp") {
+            Ok(data) => assert_eq!(data, vec![4, 5]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+
+#[derive(Debug, Default)]
+pub struct MemorySource {
+    assets: HashMap<String, Arc<[u8]>>,
+}
+
+impl MemorySource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<P>(&mut self, path: P, data: Vec<u8>) -> Option<Arc<[u8]>>
+    where
+        P: ToString,
+    {
+        self.assets.insert(path.to_string(), Arc::from(data))
+    }
+
+    pub fn with_asset<P>(mut self, path: P, data: Vec<u8>) -> Self
+    where
+        P: ToString,
+    {
+        self.assets.insert(path.to_string(), Arc::from(data));
+
+        self
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<Arc<[u8]>> {
+        self.assets.remove(path)
+    }
+}
+
+impl AssetSource for MemorySource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        self.assets
+            .get(path)
+            .map(|data| data.to_vec())
+            .ok_or(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            })
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        self.assets
+            .get(path)
+            .map(|data| Box::new(Cursor::new(Arc::clone(data))) as Box<dyn Read + Send>)
+            .ok_or(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            })
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.assets.contains_key(path)
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        self.assets
+            .get(path)
+            .map(|data| AssetSize::new(None, data.len() as u64))
+            .ok_or(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::AssetSource;
+    use crate::source::MemorySource;
+
+    #[test]
+    fn load() {
+        let source = MemorySource::new().with_asset("pages/page_1.webp", vec![0, 1, 2, 3]);
+
+        assert!(source.contains("pages/page_1.webp"));
+        assert!(!source.contains("pages/page_2.webp"));
+
+        match source.load("pages/page_1.webp") {
+            Ok(data) => assert_eq!(data, vec![0, 1, 2, 3]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        assert!(source.load("pages/page_2.webp").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::codec;
+use crate::codec::AssetSize;
+use crate::codec::AssetSource;
+use crate::codec::Stream;
+use std::io::Read;
+use std::sync::Arc;
+use zip::ZipWriter;
+
+pub struct OverlaySource {
+    layers: Vec<Arc<dyn AssetSource>>,
+}
+
+impl OverlaySource {
+    pub fn new(base: Arc<dyn AssetSource>) -> Self {
+        OverlaySource { layers: vec![base] }
+    }
+
+    pub fn push(&mut self, layer: Arc<dyn AssetSource>) {
+        self.layers.push(layer);
+    }
+
+    pub fn with_layer(mut self, layer: Arc<dyn AssetSource>) -> Self {
+        self.layers.push(layer);
+
+        self
+    }
+
+    fn find(&self, path: &str) -> codec::Result<&Arc<dyn AssetSource>> {
+        self.layers
+            .iter()
+            .rev()
+            .find(|layer| layer.contains(path))
+            .ok_or(codec::Error::AssetNotFound {
+                path: path.to_string(),
+            })
+    }
+}
+
+impl AssetSource for OverlaySource {
+    fn load(&self, path: &str) -> codec::Result<Vec<u8>> {
+        self.find(path)?.load(path)
+    }
+
+    fn open(&self, path: &str) -> codec::Result<Box<dyn Read + Send>> {
+        self.find(path)?.open(path)
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.find(path).is_ok()
+    }
+
+    fn size(&self, path: &str) -> codec::Result<AssetSize> {
+        self.find(path)?.size(path)
+    }
+
+    fn copy(
+        &self,
+        path: &str,
+        name: &str,
+        writer: &mut ZipWriter<&mut dyn Stream>,
+    ) -> codec::Result<()> {
+        self.find(path)?.copy(path, name, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source::AssetSource;
+    use crate::source::MemorySource;
+    use crate::source::OverlaySource;
+    use std::sync::Arc;
+
+    #[test]
+    fn load() {
+        let base = MemorySource::new()
+            .with_asset("pages/page_1.webp", vec![0, 1])
+            .with_asset("pages/page_2.webp", vec![2, 3]);
+
+        let edited = MemorySource::new().with_asset("pages/page_2.webp", vec![4, 5]);
+
+        let source = OverlaySource::new(Arc::new(base)).with_layer(Arc::new(edited));
+
+        match source.load("pages/page_1.webp") {
+            Ok(data) => assert_eq!(data, vec![0, 1]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        match source.load("pages/page_2.webp") {
+            Ok(data) => assert_eq!(data, vec![4, 5]),
+            Err(err) => panic!("Failed to load asset: {:?}", err),
+        }
+
+        assert!(source.load("pages/page_3.webp").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::codec;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+pub(crate) trait ReadAt: Send + Sync {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize>;
+
+    fn size(&self) -> io::Result<u64>;
+}
+
+impl<R> ReadAt for Mutex<R>
+where
+    R: Read + Seek + Send,
+{
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        let mut inner = self
+            .lock()
+            .map_err(|_| io::Error::other(codec::Error::ArchiveAcquireFailed))?;
+
+        inner.seek(SeekFrom::Start(offset))?;
+
+        inner.read(buf)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        self.lock()
+            .map_err(|_| io::Error::other(codec::Error::ArchiveAcquireFailed))?
+            .seek(SeekFrom::End(0))
+    }
+}
+
+#[cfg(unix)]
+impl ReadAt for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        std::os::unix::fs::FileExt::read_at(self, buf, offset)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        self.metadata().map(|metadata| metadata.len())
+    }
+}
+
+#[cfg(windows)]
+impl ReadAt for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        std::os::windows::fs::FileExt::seek_read(self, buf, offset)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        self.metadata().map(|metadata| metadata.len())
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Shared {
+    inner: Arc<dyn ReadAt>,
+
+    position: u64,
+}
+
+impl Shared {
+    pub(crate) fn new<R>(inner: R) -> Self
+    where
+        R: Read + Seek + Send + 'static,
+    {
+        Shared {
+            inner: Arc::new(Mutex::new(inner)),
+
+            position: 0,
+        }
+    }
+
+    #[cfg(any(unix, windows))]
+    pub(crate) fn from_file(file: File) -> Self {
+        Shared {
+            inner: Arc::new(file),
+
+            position: 0,
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub(crate) fn from_file(file: File) -> Self {
+        Shared::new(file)
+    }
+}
+
+impl Read for Shared {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read_at(buf, self.position)?;
+
+        self.position += len as u64;
+
+        Ok(len)
+    }
+}
+
+impl Seek for Shared {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => self.inner.size()?.checked_add_signed(offset),
+        }
+        .ok_or(io::ErrorKind::InvalidInput)?;
+
+        Ok(self.position)
+    }
+}